@@ -0,0 +1,173 @@
+use std::fmt::{self, Display};
+
+use serde::{Serialize, Serializer};
+
+/// The answer to a part.
+///
+/// Parts build these with `.into()` from integers, strings, or `Vec`s of either,
+/// multi-line ASCII art is made with [`Answer::art`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Answer {
+    Int(i128),
+    Text(String),
+    /// Several values, written comma separated like `4,6,3,5`.
+    List(Vec<Answer>),
+    /// Multi-line ASCII art, compared ignoring trailing whitespace and blank lines around it.
+    Art(String),
+}
+
+/// Trim trailing whitespace from each line of some ASCII art and drop blank lines around it.
+fn normalize_art(art: &str) -> String {
+    let lines = art.lines().map(str::trim_end).collect::<Vec<_>>();
+    let start = lines
+        .iter()
+        .position(|l| !l.is_empty())
+        .unwrap_or(lines.len());
+    let end = lines
+        .iter()
+        .rposition(|l| !l.is_empty())
+        .map_or(start, |i| i + 1);
+    lines[start..end].join("\n")
+}
+
+impl Answer {
+    pub fn art(art: impl Into<String>) -> Self {
+        Self::Art(art.into())
+    }
+
+    /// Whether this answer matches `expected`, as written in an example or `answers.txt`.
+    ///
+    /// Integers compare by value, lists compare item by item, and art compares
+    /// with whitespace normalized, see [`Answer::Art`].
+    pub fn matches(&self, expected: &str) -> bool {
+        match self {
+            Self::Int(n) => expected.trim().parse::<i128>() == Ok(*n),
+            Self::Text(text) => text.trim() == expected.trim(),
+            Self::List(items) => {
+                let expected = expected.trim().split(',').collect::<Vec<_>>();
+                items.len() == expected.len()
+                    && items.iter().zip(expected).all(|(a, e)| a.matches(e))
+            }
+            Self::Art(art) => normalize_art(art) == normalize_art(expected),
+        }
+    }
+}
+
+impl PartialEq<&str> for Answer {
+    fn eq(&self, other: &&str) -> bool {
+        self.matches(other)
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Int(n) => write!(f, "{n}"),
+            Self::Text(text) => write!(f, "{text}"),
+            Self::List(items) => {
+                for (i, item) in items.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ",")?;
+                    }
+                    write!(f, "{item}")?;
+                }
+                Ok(())
+            }
+            Self::Art(art) => write!(f, "{}", normalize_art(art)),
+        }
+    }
+}
+
+impl Serialize for Answer {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::Int(n) => serializer.serialize_i128(*n),
+            Self::List(items) => items.serialize(serializer),
+            Self::Text(_) | Self::Art(_) => serializer.collect_str(self),
+        }
+    }
+}
+
+macro_rules! int_answer {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(n: $t) -> Self {
+                    Self::Int(n.into())
+                }
+            }
+        )*
+    };
+}
+
+int_answer!(i8, i16, i32, i64, i128, u8, u16, u32, u64);
+
+impl From<usize> for Answer {
+    fn from(n: usize) -> Self {
+        Self::Int(n as i128)
+    }
+}
+
+impl From<isize> for Answer {
+    fn from(n: isize) -> Self {
+        Self::Int(n as i128)
+    }
+}
+
+impl From<u128> for Answer {
+    fn from(n: u128) -> Self {
+        i128::try_from(n).map_or_else(|_| Self::Text(n.to_string()), Self::Int)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(text: String) -> Self {
+        Self::Text(text)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(text: &str) -> Self {
+        Self::Text(text.to_string())
+    }
+}
+
+impl From<char> for Answer {
+    fn from(c: char) -> Self {
+        Self::Text(c.to_string())
+    }
+}
+
+impl<T: Into<Answer>> From<Vec<T>> for Answer {
+    fn from(items: Vec<T>) -> Self {
+        Self::List(items.into_iter().map(Into::into).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_answer_matches() {
+        assert!(Answer::from(42_usize).matches(" 42\n"));
+        assert!(!Answer::from(42_usize).matches("42.0"));
+        assert!(Answer::from("abc").matches("abc "));
+        assert!(Answer::from(vec![4, 6, 3]).matches("4,6,3"));
+        assert!(!Answer::from(vec![4, 6, 3]).matches("4,6"));
+        assert!(Answer::from(vec!["aa", "bb"]).matches("aa,bb"));
+        assert!(Answer::art("\n#..#  \n####\n\n").matches("#..#\n####"));
+        assert!(!Answer::art("#..#\n####").matches("#..#\n###."));
+    }
+
+    #[test]
+    fn test_answer_display() {
+        assert_eq!(Answer::from(-7_i64).to_string(), "-7");
+        assert_eq!(Answer::from(vec![1_u8, 2, 3]).to_string(), "1,2,3");
+        assert_eq!(
+            serde_json::to_string(&Answer::from(vec![1_u8, 2])).unwrap(),
+            "[1,2]"
+        );
+        assert_eq!(serde_json::to_string(&Answer::from(7_u64)).unwrap(), "7");
+    }
+}
@@ -0,0 +1,140 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Write;
+use std::io::{self, ErrorKind};
+use std::path::PathBuf;
+
+/// Accepted answers for real puzzle inputs of a single year.
+///
+/// Stored next to the inputs as `answers.txt`, one `day:part = answer` per line.
+/// Blank lines and lines starting with `#` are ignored, newlines in answers are written as `\n`.
+/// Saving keeps the file's existing lines, only changed answers are rewritten and new ones appended.
+#[derive(Clone, Debug)]
+pub struct Answers {
+    path: PathBuf,
+    contents: String,
+    answers: BTreeMap<(usize, usize), String>,
+}
+
+impl Answers {
+    pub const FILE_NAME: &str = "answers.txt";
+
+    /// Load answers from `path`, a missing file is treated as having no answers yet.
+    pub fn load(path: impl Into<PathBuf>) -> io::Result<Self> {
+        let path = path.into();
+        let contents = match std::fs::read_to_string(&path) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == ErrorKind::NotFound => String::new(),
+            Err(e) => return Err(e),
+        };
+
+        let answers = contents
+            .lines()
+            .enumerate()
+            .map(|(i, l)| (i + 1, l.trim()))
+            .filter(|(_, l)| !Self::is_skipped(l))
+            .map(|(line_num, line)| {
+                Self::parse_line(line).ok_or_else(|| {
+                    io::Error::new(
+                        ErrorKind::InvalidData,
+                        format!(
+                            "{}:{line_num}: expected `day:part = answer`, got `{line}`",
+                            path.display()
+                        ),
+                    )
+                })
+            })
+            .collect::<io::Result<_>>()?;
+
+        Ok(Self {
+            path,
+            contents,
+            answers,
+        })
+    }
+
+    fn is_skipped(line: &str) -> bool {
+        line.is_empty() || line.starts_with('#')
+    }
+
+    fn parse_line(line: &str) -> Option<((usize, usize), String)> {
+        let (key, answer) = line.split_once('=')?;
+        let (day, part) = key.trim().split_once(':')?;
+        let day = day.parse().ok()?;
+        let part = part.parse().ok()?;
+        Some(((day, part), answer.trim().replace("\\n", "\n")))
+    }
+
+    pub fn get(&self, day: usize, part: usize) -> Option<&str> {
+        self.answers.get(&(day, part)).map(String::as_str)
+    }
+
+    pub fn insert(&mut self, day: usize, part: usize, answer: String) {
+        self.answers.insert((day, part), answer);
+    }
+
+    pub fn save(&self) -> io::Result<()> {
+        let mut contents = String::new();
+        let mut written = BTreeSet::new();
+        for line in self.contents.lines() {
+            let trimmed = line.trim();
+            let recorded = (!Self::is_skipped(trimmed))
+                .then(|| Self::parse_line(trimmed))
+                .flatten();
+            if let Some((key, answer)) = recorded {
+                written.insert(key);
+                if let Some(changed) = self.answers.get(&key).filter(|a| **a != answer) {
+                    Self::write_line(&mut contents, key, changed);
+                    continue;
+                }
+            }
+            writeln!(contents, "{line}").unwrap();
+        }
+        for (key, answer) in self.answers.iter() {
+            if !written.contains(key) {
+                Self::write_line(&mut contents, *key, answer);
+            }
+        }
+        if let Some(parent) = self.path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(&self.path, contents)
+    }
+
+    fn write_line(contents: &mut String, (day, part): (usize, usize), answer: &str) {
+        let answer = answer.replace('\n', "\\n");
+        writeln!(contents, "{day}:{part} = {answer}").unwrap();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_save_keeps_comments() {
+        let dir = std::env::temp_dir().join(format!("advent-answers-{}", std::process::id()));
+        let path = dir.join(Answers::FILE_NAME);
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(
+            &path,
+            "# accepted by alice\n1:1 = 11\n\n# wrong at first\n2:1 = 7\n",
+        )
+        .unwrap();
+
+        let mut answers = Answers::load(&path).unwrap();
+        assert_eq!(answers.get(1, 1), Some("11"));
+        answers.insert(1, 2, "31".to_string());
+        answers.insert(2, 1, "8".to_string());
+        answers.insert(3, 1, "a\nb".to_string());
+        answers.save().unwrap();
+
+        assert_eq!(
+            std::fs::read_to_string(&path).unwrap(),
+            "# accepted by alice\n1:1 = 11\n\n# wrong at first\n2:1 = 8\n1:2 = 31\n3:1 = a\\nb\n"
+        );
+        let answers = Answers::load(&path).unwrap();
+        assert_eq!(answers.get(3, 1), Some("a\nb"));
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,358 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use indicatif::{ProgressBar, ProgressStyle};
+use serde::{Deserialize, Serialize};
+
+use crate::report::InputKind;
+
+/// How many of the slowest days get highlighted in the bench report.
+const SLOWEST_DAYS: usize = 3;
+
+/// The goal every year's total bench time should stay under.
+const YEAR_GOAL: Duration = Duration::from_secs(1);
+
+/// How long a single timed sample should roughly take.
+///
+/// Fast routines are run in batches so timer overhead doesn't dominate the measurement.
+const SAMPLE_TARGET: Duration = Duration::from_micros(100);
+const MAX_BATCH: usize = 10_000;
+const MIN_SAMPLES: usize = 5;
+
+const PROGRESS_TEMPLATE: &str = "{spinner} {wide_msg} [{bar:100.green/cyan}]";
+const PROGRESS_CHARS: &str = "=>-";
+
+/// How long to warm up and measure for when benchmarking.
+#[derive(Clone, Copy, Debug)]
+pub struct BenchConfig {
+    pub warmup: Duration,
+    pub measure: Duration,
+    /// Measure a fixed number of iterations instead of for `measure`.
+    pub iterations: Option<usize>,
+    /// Skip parts whose first, unmeasured run takes longer than this.
+    pub timeout: Option<Duration>,
+}
+
+impl Default for BenchConfig {
+    fn default() -> Self {
+        Self {
+            warmup: Duration::from_secs(1),
+            measure: Duration::from_secs(5),
+            iterations: None,
+            timeout: None,
+        }
+    }
+}
+
+/// Serialize durations as a whole number of nanoseconds.
+pub(crate) mod nanos {
+    use std::time::Duration;
+
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(d: &Duration, s: S) -> Result<S::Ok, S::Error> {
+        s.serialize_u64(d.as_nanos() as u64)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(d: D) -> Result<Duration, D::Error> {
+        u64::deserialize(d).map(Duration::from_nanos)
+    }
+
+    pub mod option {
+        use std::time::Duration;
+
+        use serde::Serializer;
+
+        pub fn serialize<S: Serializer>(d: &Option<Duration>, s: S) -> Result<S::Ok, S::Error> {
+            match d {
+                Some(d) => super::serialize(d, s),
+                None => s.serialize_none(),
+            }
+        }
+    }
+}
+
+/// Summary statistics of the per-iteration time over every sample.
+///
+/// Times are written as whole nanoseconds, baselines saved before they had an `_ns` suffix still load.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize)]
+pub struct Stats {
+    pub samples: usize,
+    pub iterations: usize,
+    #[serde(rename = "mean_ns", alias = "mean", with = "nanos")]
+    pub mean: Duration,
+    #[serde(rename = "median_ns", alias = "median", with = "nanos")]
+    pub median: Duration,
+    #[serde(rename = "min_ns", alias = "min", with = "nanos")]
+    pub min: Duration,
+    #[serde(rename = "max_ns", alias = "max", with = "nanos")]
+    pub max: Duration,
+    #[serde(rename = "std_dev_ns", alias = "std_dev", with = "nanos")]
+    pub std_dev: Duration,
+    #[serde(rename = "p95_ns", alias = "p95", with = "nanos")]
+    pub p95: Duration,
+    #[serde(rename = "p99_ns", alias = "p99", with = "nanos")]
+    pub p99: Duration,
+}
+
+impl Stats {
+    /// Compute stats from per-iteration sample times, `samples` must not be empty.
+    pub fn new(mut samples: Vec<Duration>, iterations: usize) -> Self {
+        samples.sort_unstable();
+        let n = samples.len();
+        let nanos = samples.iter().map(|d| d.as_secs_f64() * 1e9);
+        let mean = nanos.clone().sum::<f64>() / n as f64;
+        let variance = if n > 1 {
+            nanos.map(|x| (x - mean).powi(2)).sum::<f64>() / (n - 1) as f64
+        } else {
+            0.0
+        };
+        let percentile = |p: f64| samples[((p * n as f64).ceil() as usize).clamp(1, n) - 1];
+
+        Self {
+            samples: n,
+            iterations,
+            mean: Duration::from_secs_f64(mean / 1e9),
+            median: percentile(0.5),
+            min: samples[0],
+            max: samples[n - 1],
+            std_dev: Duration::from_secs_f64(variance.sqrt() / 1e9),
+            p95: percentile(0.95),
+            p99: percentile(0.99),
+        }
+    }
+}
+
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:.2?} ± {:.2?} (median {:.2?}, min {:.2?}, max {:.2?}, p95 {:.2?}, p99 {:.2?}, N = {})",
+            self.mean,
+            self.std_dev,
+            self.median,
+            self.min,
+            self.max,
+            self.p95,
+            self.p99,
+            self.iterations
+        )
+    }
+}
+
+/// Benchmark `routine`, calling `setup` outside of the timed region to make its input.
+///
+/// After warming up, iterations are timed in batches sized so each sample takes about
+/// [`SAMPLE_TARGET`], with inputs made and outputs dropped outside of the timed region.
+pub fn bench_with_setup<I, O>(
+    config: &BenchConfig,
+    label: &str,
+    mut setup: impl FnMut() -> I,
+    mut routine: impl FnMut(I) -> O,
+) -> Stats {
+    let style = ProgressStyle::with_template(PROGRESS_TEMPLATE)
+        .unwrap()
+        .progress_chars(PROGRESS_CHARS);
+    let progress = ProgressBar::new(100).with_style(style);
+    progress.set_message(format!("{label}: Warming up"));
+
+    let warmup_start = Instant::now();
+    let mut warmup_iterations = 0_u32;
+    let mut warmup_time = Duration::ZERO;
+    loop {
+        let input = setup();
+        let start = Instant::now();
+        let output = black_box(routine(black_box(input)));
+        warmup_time += start.elapsed();
+        drop(output);
+        warmup_iterations += 1;
+        if warmup_start.elapsed() >= config.warmup {
+            break;
+        }
+    }
+
+    let per_iteration = (warmup_time / warmup_iterations).as_nanos().max(1);
+    let batch = ((SAMPLE_TARGET.as_nanos() / per_iteration) as usize)
+        .clamp(1, MAX_BATCH)
+        .min(config.iterations.unwrap_or(usize::MAX).max(1));
+
+    let mut samples = Vec::with_capacity(1000);
+    let mut iterations = 0;
+    let mut inputs = Vec::with_capacity(batch);
+    let mut outputs = Vec::with_capacity(batch);
+    let measure_start = Instant::now();
+    loop {
+        inputs.extend((0..batch).map(|_| setup()));
+        let start = Instant::now();
+        for input in inputs.drain(..) {
+            outputs.push(black_box(routine(black_box(input))));
+        }
+        let elapsed = start.elapsed();
+        outputs.clear();
+
+        samples.push(elapsed / batch as u32);
+        iterations += batch;
+
+        let done = match config.iterations {
+            Some(target) => {
+                progress.set_position((iterations * 100 / target.max(1)) as u64);
+                iterations >= target
+            }
+            None => {
+                let fraction = measure_start.elapsed().as_secs_f64()
+                    / config.measure.as_secs_f64().max(f64::EPSILON);
+                progress.set_position((fraction * 100.0) as u64);
+                measure_start.elapsed() >= config.measure && samples.len() >= MIN_SAMPLES
+            }
+        };
+        progress.set_message(format!("{label}: {iterations} Runs"));
+        if done {
+            break;
+        }
+    }
+    progress.finish_and_clear();
+
+    Stats::new(samples, iterations)
+}
+
+/// Benchmark `routine` on its own.
+pub fn bench<O>(config: &BenchConfig, label: &str, mut routine: impl FnMut() -> O) -> Stats {
+    bench_with_setup(config, label, || (), |_| routine())
+}
+
+/// Time stats for parsing and solving one part.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize)]
+pub struct BenchTimes {
+    pub parse: Stats,
+    pub solve: Stats,
+}
+
+impl BenchTimes {
+    /// Mean time to parse and solve.
+    pub fn total(&self) -> Duration {
+        self.parse.mean + self.solve.mean
+    }
+}
+
+#[derive(Clone, Copy, Debug, Serialize, Deserialize)]
+pub struct BenchResult {
+    pub year: usize,
+    pub day: usize,
+    pub part: usize,
+    /// Whether the part was benched against its example or a real input.
+    pub input: InputKind,
+    pub times: BenchTimes,
+}
+
+fn day_totals(results: &[BenchResult]) -> Vec<((usize, usize), Duration)> {
+    let mut days = BTreeMap::<(usize, usize), Duration>::new();
+    for r in results {
+        *days.entry((r.year, r.day)).or_default() += r.times.total();
+    }
+    let mut days = days.into_iter().collect::<Vec<_>>();
+    days.sort_by_key(|(_, total)| std::cmp::Reverse(*total));
+    days
+}
+
+/// Print every benched part sorted from slowest to fastest, followed by per-year totals.
+///
+/// Parts of the slowest days are marked with a `*`.
+pub fn print_bench_report(results: &[BenchResult]) {
+    if results.is_empty() {
+        println!("Nothing was benched");
+        return;
+    }
+
+    let days = day_totals(results);
+    let slowest = days
+        .iter()
+        .take(SLOWEST_DAYS)
+        .map(|(day, _)| *day)
+        .collect::<Vec<_>>();
+
+    let mut sorted = results.to_vec();
+    sorted.sort_by_key(|r| std::cmp::Reverse(r.times.total()));
+
+    println!();
+    println!(
+        "  {:<4}  {:>3}  {:>4}  {:>12}  {:>12}  {:>12}  {:>12}",
+        "Year", "Day", "Part", "Parse", "Solve", "Solve σ", "Total"
+    );
+    for r in sorted.iter() {
+        let marker = if slowest.contains(&(r.year, r.day)) {
+            '*'
+        } else {
+            ' '
+        };
+        println!(
+            "{marker} {:<4}  {:>3}  {:>4}  {:>12}  {:>12}  {:>12}  {:>12}",
+            r.year,
+            r.day,
+            r.part,
+            format!("{:.2?}", r.times.parse.mean),
+            format!("{:.2?}", r.times.solve.mean),
+            format!("{:.2?}", r.times.solve.std_dev),
+            format!("{:.2?}", r.times.total()),
+        );
+    }
+
+    println!();
+    let mut years = BTreeMap::<usize, (Duration, usize)>::new();
+    for r in results {
+        let (total, parts) = years.entry(r.year).or_default();
+        *total += r.times.total();
+        *parts += 1;
+    }
+    for (year, (total, parts)) in years {
+        let goal = if total < YEAR_GOAL { "under" } else { "OVER" };
+        println!("Year {year}: {total:.2?} across {parts} parts ({goal} {YEAR_GOAL:?})");
+    }
+
+    println!();
+    println!("Slowest days:");
+    for ((year, day), total) in days.iter().take(SLOWEST_DAYS) {
+        println!("* {year} Day {day}: {total:.2?}");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stats() {
+        let samples = (1..=100).map(Duration::from_micros).collect::<Vec<_>>();
+        let stats = Stats::new(samples, 1000);
+        assert_eq!(stats.samples, 100);
+        assert_eq!(stats.iterations, 1000);
+        assert_eq!(stats.min, Duration::from_micros(1));
+        assert_eq!(stats.max, Duration::from_micros(100));
+        assert_eq!(stats.median, Duration::from_micros(50));
+        assert_eq!(stats.p95, Duration::from_micros(95));
+        assert_eq!(stats.p99, Duration::from_micros(99));
+        assert_eq!(stats.mean.as_nanos(), 50_500);
+        assert_eq!(stats.std_dev.as_nanos(), 29_011);
+
+        let json = serde_json::to_value(stats).unwrap();
+        assert_eq!(json["mean_ns"], 50_500);
+        let old = r#"{"samples":1,"iterations":1,"mean":5,"median":5,"min":5,"max":5,"std_dev":0,"p95":5,"p99":5}"#;
+        let old = serde_json::from_str::<Stats>(old).unwrap();
+        assert_eq!(old.mean, Duration::from_nanos(5));
+    }
+
+    #[test]
+    fn test_bench_iterations() {
+        let config = BenchConfig {
+            warmup: Duration::ZERO,
+            measure: Duration::ZERO,
+            iterations: Some(10),
+            timeout: None,
+        };
+        let mut setups = 0;
+        let stats = bench_with_setup(&config, "test", || setups += 1, |_| ());
+        assert!(stats.iterations >= 10);
+        assert_eq!(setups, stats.iterations + 1);
+    }
+}
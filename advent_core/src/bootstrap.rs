@@ -1,11 +1,8 @@
-use std::path::Path;
-
-use regex::Regex;
-
-use crate::MAX_DAY;
+use std::io;
+use std::path::{Path, PathBuf};
 
 const DAY_TEMPLATE: &str = "
-use advent_core::{Day, day_stuff, ex_for_day};
+use advent_core::{Answer, Day, day_stuff, ex_for_day};
 
 pub struct Day{day};
 
@@ -13,11 +10,13 @@ impl Day for Day{day} {
 
     day_stuff!({day}, \"\", \"\");
 
-    fn part_1(_input: Self::Input) -> Option<String> {
+    const TITLE: &'static str = \"\";
+
+    fn part_1(_input: &Self::Input<'_>) -> Option<Answer> {
         None
     }
 
-    fn part_2(_input: Self::Input) -> Option<String> {
+    fn part_2(_input: &Self::Input<'_>) -> Option<Answer> {
         None
     }
 }
@@ -47,129 +46,247 @@ macros = { path = \"../../macros\" }
 utils = { path = \"../../utils\" }
 ";
 
-fn make_day(folder: &Path, day: usize) {
-    let day = day.to_string();
-
-    let day_path = folder.join(format!("day_{}.rs", day));
-
-    let contents = DAY_TEMPLATE.replace("{day}", &day);
-
-    std::fs::write(day_path, contents).unwrap();
+const BUILD_TEMPLATE: &str = "
+fn main() {
+    // year! counts the day_N.rs files and reads the examples folder to find extra example cases
+    println!(\"cargo:rerun-if-changed=src\");
 }
+";
 
-fn make_example(folder: &Path, day: usize) {
-    let day = day.to_string();
-
-    let example_path = folder.join(format!("day_{}", day));
-
-    let part_1_path = example_path.join("1.txt");
-    let part_2_path = example_path.join("2.txt");
-
-    std::fs::create_dir_all(&example_path).unwrap();
-    std::fs::write(part_1_path, "").unwrap();
-    std::fs::write(part_2_path, "").unwrap();
+/// How `advent new` treats files that already exist.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct NewYearConfig {
+    /// Overwrite existing files with their templates instead of keeping them.
+    pub force: bool,
+    /// Work out what would change without writing anything.
+    pub dry_run: bool,
 }
 
-fn make_days(folder: &Path) {
-    for day in 1..=MAX_DAY {
-        make_day(folder, day);
-    }
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FileAction {
+    Create,
+    Overwrite,
+    /// Add to an existing file, leaving the rest of it as is.
+    Update,
+    /// The file already exists and is left alone.
+    Keep,
 }
 
-fn make_examples(folder: &Path) {
-    let examples_path = folder.join("examples");
-    for day in 1..=MAX_DAY {
-        make_example(&examples_path, day);
+impl FileAction {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Create => "create",
+            Self::Overwrite => "overwrite",
+            Self::Update => "update",
+            Self::Keep => "keep",
+        }
     }
 }
 
-fn make_lib(folder: &Path, year: &str) {
-    let lib_path = folder.join("lib.rs");
-
-    let contents = YEAR_TEMPLATE.replace("{year}", year);
-
-    std::fs::write(lib_path, contents).unwrap();
-}
-
-fn make_main(folder: &Path, year: &str) {
-    let main_path = folder.join("main.rs");
-
-    let contents = RUNNER_TEMPLATE.replace("{year}", year);
-
-    std::fs::write(main_path, contents).unwrap();
+/// A file `advent new` made, or would make in a dry run.
+#[derive(Debug)]
+pub struct FileChange {
+    pub path: PathBuf,
+    pub action: FileAction,
+    /// Why writing the file failed, if it did.
+    pub error: Option<io::Error>,
 }
 
-fn make_src(folder: &Path, year: &str) {
-    let src_path = folder.join("src");
-
-    std::fs::create_dir_all(&src_path).unwrap();
-
-    make_days(&src_path);
-    make_examples(&src_path);
-    make_lib(&src_path, year);
-    make_main(&src_path, year);
+/// Collects the changes made while scaffolding a year.
+struct Scaffold {
+    config: NewYearConfig,
+    changes: Vec<FileChange>,
 }
 
-fn make_cargo(folder: &Path, year: &str) {
-    let cargo_path = folder.join("Cargo.toml");
+impl Scaffold {
+    fn push(&mut self, path: PathBuf, action: FileAction, error: Option<io::Error>) {
+        self.changes.push(FileChange {
+            path,
+            action,
+            error,
+        });
+    }
 
-    let contents = CARGO_TEMPLATE.replace("{year}", year);
+    /// Write a file from a template, keeping it if it exists unless forced.
+    fn write(&mut self, path: PathBuf, contents: &str) {
+        let action = match (path.exists(), self.config.force) {
+            (false, _) => FileAction::Create,
+            (true, true) => FileAction::Overwrite,
+            (true, false) => FileAction::Keep,
+        };
+
+        let error = if self.config.dry_run || action == FileAction::Keep {
+            None
+        } else {
+            path.parent()
+                .map_or(Ok(()), std::fs::create_dir_all)
+                .and_then(|_| std::fs::write(&path, contents))
+                .err()
+        };
+
+        self.push(path, action, error);
+    }
 
-    std::fs::write(cargo_path, contents).unwrap();
+    /// Add the year's crate to the runner's dependencies if it isn't there, `global_runner!`
+    /// needs it to use the year.
+    ///
+    /// Reads `Cargo.toml` as it is now, so edits made since `advent` was built are kept.
+    fn add_year_dependency(&mut self, year: &str) {
+        let path = PathBuf::from("Cargo.toml");
+        let cargo = match std::fs::read_to_string(&path) {
+            Ok(cargo) => cargo,
+            Err(e) => return self.push(path, FileAction::Update, Some(e)),
+        };
+
+        let name = format!("y_{year}");
+        let has_dependency = cargo.lines().any(|line| {
+            line.trim_start()
+                .strip_prefix(&name)
+                .is_some_and(|rest| rest.trim_start().starts_with(['=', '.']))
+        });
+        if has_dependency {
+            return self.push(path, FileAction::Keep, None);
+        }
+
+        let Some(deps) = cargo.find("[dependencies]") else {
+            let e = io::Error::new(io::ErrorKind::InvalidData, "no [dependencies] table");
+            return self.push(path, FileAction::Update, Some(e));
+        };
+        let at = deps + "[dependencies]".len();
+        let new_dep = format!("\n{name} = {{ path = \"years/{year}\" }}");
+        let cargo = format!("{}{new_dep}{}", &cargo[..at], &cargo[at..]);
+
+        let error = if self.config.dry_run {
+            None
+        } else {
+            std::fs::write(&path, cargo).err()
+        };
+        self.push(path, FileAction::Update, error);
+    }
 }
 
-fn replace_year_list(new_year: &str) {
-    let main = include_str!("../../src/main.rs");
-
-    let global_runner_pattern = Regex::new(r"global_runner!\(([\d,]+)\)").unwrap();
-
-    let matches = global_runner_pattern.captures(main).unwrap();
-
-    let full = matches.get(0).unwrap().as_str();
-
-    let mut years = matches
-        .get(1)
-        .unwrap()
-        .as_str()
-        .split(',')
-        .map(|s| s.parse::<usize>().unwrap())
-        .collect::<Vec<_>>();
-
-    years.push(new_year.parse::<usize>().unwrap());
-
-    years.sort();
-
-    let new_years = years
-        .iter()
-        .map(|y| y.to_string())
-        .collect::<Vec<_>>()
-        .join(",");
+/// Scaffold `years/<year>` with `days` stub days, `year!` counts them when it's built.
+///
+/// Files that already exist are kept unless `config.force` is set, so running this on an
+/// existing year adds any days it's missing without touching the rest. Every file is
+/// attempted even if some fail, check each [`FileChange::error`].
+pub fn make_year(year: &str, days: usize, config: NewYearConfig) -> Vec<FileChange> {
+    let mut scaffold = Scaffold {
+        config,
+        changes: vec![],
+    };
+
+    let year_path = Path::new("years").join(year);
+    let src_path = year_path.join("src");
+    let examples_path = src_path.join("examples");
+
+    for day in 1..=days {
+        let contents = DAY_TEMPLATE.replace("{day}", &day.to_string());
+        scaffold.write(src_path.join(format!("day_{day}.rs")), &contents);
+    }
 
-    let new_main = main.replace(full, &format!("global_runner!({})", new_years));
+    for day in 1..=days {
+        let example_path = examples_path.join(format!("day_{day}"));
+        scaffold.write(example_path.join("1.txt"), "");
+        scaffold.write(example_path.join("2.txt"), "");
+    }
 
-    std::fs::write("src/main.rs", new_main).unwrap();
+    scaffold.write(
+        src_path.join("lib.rs"),
+        &YEAR_TEMPLATE.replace("{year}", year),
+    );
+    scaffold.write(
+        src_path.join("main.rs"),
+        &RUNNER_TEMPLATE.replace("{year}", year),
+    );
+    scaffold.write(
+        year_path.join("Cargo.toml"),
+        &CARGO_TEMPLATE.replace("{year}", year),
+    );
+    scaffold.write(year_path.join("build.rs"), BUILD_TEMPLATE);
+
+    scaffold.add_year_dependency(year);
+
+    scaffold.changes
 }
 
-fn replace_cargo_dependencies(new_year: &str) {
-    let cargo = include_str!("../../Cargo.toml");
-
-    let new_dep = format!("y_{year} = {{ path = \"years/{year}\" }}", year = new_year);
+/// Print what `advent new` did, or would do in a dry run, and any files that couldn't be written.
+pub fn print_year_changes(changes: &[FileChange], config: NewYearConfig) {
+    if config.dry_run {
+        println!("Dry run, nothing was written");
+    }
 
-    let cargo = cargo.replace("[dependencies]", &format!("[dependencies]\n{}", new_dep));
+    for change in changes {
+        match &change.error {
+            Some(e) => eprintln!("{:<9} {}: {e}", "failed", change.path.display()),
+            None if change.action == FileAction::Keep => {
+                println!(
+                    "{:<9} {} (exists)",
+                    change.action.as_str(),
+                    change.path.display()
+                )
+            }
+            None => println!("{:<9} {}", change.action.as_str(), change.path.display()),
+        }
+    }
 
-    std::fs::write("Cargo.toml", cargo).unwrap();
+    let count = |action| {
+        changes
+            .iter()
+            .filter(|c| c.action == action && c.error.is_none())
+            .count()
+    };
+    let failed = changes.iter().filter(|c| c.error.is_some()).count();
+    println!(
+        "\n{} created, {} overwritten, {} updated, {} kept, {failed} failed",
+        count(FileAction::Create),
+        count(FileAction::Overwrite),
+        count(FileAction::Update),
+        count(FileAction::Keep),
+    );
+    if count(FileAction::Keep) > 0 && !config.force && !config.dry_run {
+        println!("Existing files were kept, pass --force to overwrite them");
+    }
 }
 
-pub fn make_year(year: &str) {
-    let cwd = std::env::current_dir().unwrap();
-
-    let year_path = cwd.join(format!("years/{}", year));
-
-    std::fs::create_dir_all(&year_path).unwrap();
-
-    make_src(&year_path, year);
-    make_cargo(&year_path, year);
-
-    replace_cargo_dependencies(year);
-    replace_year_list(year);
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scaffold_keeps_existing() {
+        let dir = std::env::temp_dir().join(format!("advent-scaffold-{}", std::process::id()));
+        let existing = dir.join("day_1.rs");
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(&existing, "solved").unwrap();
+
+        let mut scaffold = Scaffold {
+            config: NewYearConfig::default(),
+            changes: vec![],
+        };
+        scaffold.write(existing.clone(), "stub");
+        scaffold.write(dir.join("day_2.rs"), "stub");
+
+        let actions = scaffold
+            .changes
+            .iter()
+            .map(|c| c.action)
+            .collect::<Vec<_>>();
+        assert_eq!(actions, vec![FileAction::Keep, FileAction::Create]);
+        assert_eq!(std::fs::read_to_string(&existing).unwrap(), "solved");
+        assert_eq!(
+            std::fs::read_to_string(dir.join("day_2.rs")).unwrap(),
+            "stub"
+        );
+
+        scaffold.config = NewYearConfig {
+            force: true,
+            dry_run: true,
+        };
+        scaffold.write(existing.clone(), "stub");
+        assert_eq!(scaffold.changes[2].action, FileAction::Overwrite);
+        assert_eq!(std::fs::read_to_string(&existing).unwrap(), "solved");
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
 }
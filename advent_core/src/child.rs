@@ -0,0 +1,243 @@
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::path::PathBuf;
+use std::process::{Command, Stdio};
+use std::sync::{OnceLock, mpsc};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use serde::{Deserialize, Serialize};
+
+use crate::answer::Answer;
+use crate::day::{Failure, Solution};
+use crate::parallel::WORKER_STACK_SIZE;
+use crate::registry::DayEntry;
+
+/// The hidden subcommand a runner is re-run with to solve parts in a child process.
+///
+/// It's passed the year, the day, `--example` if there's no input, and the parts to solve,
+/// and should hand them to [`serve_child`].
+pub const CHILD_COMMAND: &str = "solve-child";
+
+static CHILD_EXE: OnceLock<PathBuf> = OnceLock::new();
+
+/// Run parts that have a timeout in a child process of `exe` instead of on a worker thread,
+/// so a part that runs past its timeout is killed rather than left running alongside later days.
+///
+/// `exe` must handle [`CHILD_COMMAND`]. Without this, as in tests, parts fall back to
+/// [`crate::Day::run_parts_with`].
+pub fn enable_child_processes(exe: PathBuf) {
+    let _ = CHILD_EXE.set(exe);
+}
+
+/// A [`Solution`] as sent from a child process, keeping which kind of [`Answer`] it was.
+#[derive(Serialize, Deserialize)]
+struct ChildSolution {
+    answer: Option<ChildAnswer>,
+    parse_time: Option<Duration>,
+    solve_time: Duration,
+    failure: Option<Failure>,
+}
+
+#[derive(Serialize, Deserialize)]
+enum ChildAnswer {
+    Int(i128),
+    Text(String),
+    List(Vec<ChildAnswer>),
+    Art(String),
+}
+
+impl From<&Answer> for ChildAnswer {
+    fn from(answer: &Answer) -> Self {
+        match answer {
+            Answer::Int(n) => Self::Int(*n),
+            Answer::Text(text) => Self::Text(text.clone()),
+            Answer::List(items) => Self::List(items.iter().map(Self::from).collect()),
+            Answer::Art(art) => Self::Art(art.clone()),
+        }
+    }
+}
+
+impl From<ChildAnswer> for Answer {
+    fn from(answer: ChildAnswer) -> Self {
+        match answer {
+            ChildAnswer::Int(n) => Self::Int(n),
+            ChildAnswer::Text(text) => Self::Text(text),
+            ChildAnswer::List(items) => Self::List(items.into_iter().map(Self::from).collect()),
+            ChildAnswer::Art(art) => Self::Art(art),
+        }
+    }
+}
+
+impl From<&Solution> for ChildSolution {
+    fn from(solution: &Solution) -> Self {
+        Self {
+            answer: solution.answer.as_ref().map(ChildAnswer::from),
+            parse_time: solution.parse_time,
+            solve_time: solution.solve_time,
+            failure: solution.failure.clone(),
+        }
+    }
+}
+
+impl From<ChildSolution> for Solution {
+    fn from(solution: ChildSolution) -> Self {
+        Self {
+            answer: solution.answer.map(Answer::from),
+            parse_time: solution.parse_time,
+            solve_time: solution.solve_time,
+            failure: solution.failure,
+        }
+    }
+}
+
+/// Solve parts of a day for the parent process, reading the input from stdin unless `example`,
+/// and writing each part's solution to stdout as a line of JSON as soon as it's solved.
+pub fn serve_child(entry: &DayEntry, parts: &[usize], example: bool) -> io::Result<()> {
+    let input = if example {
+        None
+    } else {
+        let mut input = String::new();
+        io::stdin().read_to_string(&mut input)?;
+        Some(input)
+    };
+
+    thread::scope(|s| {
+        thread::Builder::new()
+            .stack_size(WORKER_STACK_SIZE)
+            .spawn_scoped(s, || {
+                let mut stdout = io::stdout().lock();
+                let mut result = Ok(());
+                (entry.solve_each)(parts, input.as_deref(), &mut |solution| {
+                    if result.is_ok() {
+                        let line = serde_json::to_string(&ChildSolution::from(&solution))
+                            .expect("Failed to serialize solution");
+                        result = writeln!(stdout, "{line}").and_then(|_| stdout.flush());
+                    }
+                });
+                result
+            })
+            .expect("Failed to spawn worker thread")
+            .join()
+            .unwrap_or(Ok(()))
+    })
+}
+
+/// Solve parts of a day in a child process, killing it if a part runs past `timeout`.
+///
+/// Like [`crate::Day::run_parts_with`], the parts after one that times out continue in a
+/// fresh child. `None` if child processes aren't enabled or can't be started.
+pub(crate) fn solve_in_child(
+    year: usize,
+    day: usize,
+    parts: &[usize],
+    input: Option<&str>,
+    timeout: Duration,
+) -> Option<Vec<Solution>> {
+    let exe = CHILD_EXE.get()?;
+    let mut solutions = Vec::with_capacity(parts.len());
+
+    while solutions.len() < parts.len() {
+        let remaining = &parts[solutions.len()..];
+        let mut command = Command::new(exe);
+        command
+            .args([CHILD_COMMAND, &year.to_string(), &day.to_string()])
+            .args(input.is_none().then_some("--example"))
+            .args(remaining.iter().map(usize::to_string))
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped());
+
+        let mut child = match command.spawn() {
+            Ok(child) => child,
+            Err(e) if solutions.is_empty() => {
+                eprintln!("Failed to start a child process, running on a thread instead: {e}");
+                return None;
+            }
+            Err(e) => {
+                solutions.push(Solution {
+                    answer: None,
+                    parse_time: None,
+                    solve_time: Duration::ZERO,
+                    failure: Some(Failure::Panic(format!(
+                        "Failed to start a child process: {e}"
+                    ))),
+                });
+                continue;
+            }
+        };
+
+        // The child reads all of its input before solving anything, and may exit early
+        // without reading it if it can't find the day, so write errors are ignored
+        let mut stdin = child.stdin.take().unwrap();
+        let _ = stdin.write_all(input.unwrap_or_default().as_bytes());
+        drop(stdin);
+
+        let stdout = child.stdout.take().unwrap();
+        let (tx, rx) = mpsc::channel();
+        thread::spawn(move || {
+            for line in BufReader::new(stdout).lines().map_while(Result::ok) {
+                let Ok(solution) = serde_json::from_str::<ChildSolution>(&line) else {
+                    break;
+                };
+                if tx.send(Solution::from(solution)).is_err() {
+                    break;
+                }
+            }
+        });
+
+        for _ in remaining {
+            let instant = Instant::now();
+            match rx.recv_timeout(timeout) {
+                Ok(solution) => solutions.push(solution),
+                Err(mpsc::RecvTimeoutError::Timeout) => {
+                    let _ = child.kill();
+                    solutions.push(Solution {
+                        answer: None,
+                        parse_time: None,
+                        solve_time: instant.elapsed(),
+                        failure: Some(Failure::Timeout),
+                    });
+                    break;
+                }
+                Err(mpsc::RecvTimeoutError::Disconnected) => {
+                    // Panics are caught in the child, so this is a crash like a stack overflow
+                    let status = child
+                        .wait()
+                        .map_or_else(|e| e.to_string(), |s| s.to_string());
+                    solutions.push(Solution {
+                        answer: None,
+                        parse_time: None,
+                        solve_time: instant.elapsed(),
+                        failure: Some(Failure::Panic(format!("Child process crashed, {status}"))),
+                    });
+                    break;
+                }
+            }
+        }
+
+        let _ = child.wait();
+    }
+
+    Some(solutions)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_child_solution_round_trip() {
+        let solution = Solution {
+            answer: Some(Answer::List(vec![Answer::Int(4), Answer::art("#.\n.#")])),
+            parse_time: None,
+            solve_time: Duration::from_nanos(1234),
+            failure: Some(Failure::Error("line 1: bad".to_string())),
+        };
+
+        let line = serde_json::to_string(&ChildSolution::from(&solution)).unwrap();
+        let sent = Solution::from(serde_json::from_str::<ChildSolution>(&line).unwrap());
+        assert_eq!(sent.answer, solution.answer);
+        assert_eq!(sent.parse_time, None);
+        assert_eq!(sent.solve_time, solution.solve_time);
+        assert_eq!(sent.failure, solution.failure);
+    }
+}
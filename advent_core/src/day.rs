@@ -1,6 +1,18 @@
-use std::time::Instant;
-
-use indicatif::ProgressStyle;
+use std::any::Any;
+use std::borrow::Cow;
+use std::cell::Cell;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::{Once, mpsc};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use serde::{Deserialize, Serialize};
+
+use crate::answer::Answer;
+use crate::bench::{BenchConfig, BenchTimes, bench};
+use crate::error::InputError;
+use crate::inputs::normalize_input;
+use crate::parallel::WORKER_STACK_SIZE;
 
 #[macro_export]
 macro_rules! ex_for_day {
@@ -20,13 +32,13 @@ macro_rules! day_stuff {
     ($day:literal, $e_1:literal, $e_2:literal) => {
         day_stuff!($day, $e_1, $e_2, String);
 
-        fn parse_input(input: &str) -> Self::Input {
-            input.to_string()
+        fn try_parse_input(input: &str) -> Result<Self::Input<'_>, $crate::InputError> {
+            Ok(input.to_string())
         }
     };
 
     ($day:literal, $e_1:literal, $e_2:literal, $i: ty) => {
-        type Input = $i;
+        type Input<'a> = $i;
 
         const DAY: usize = $day;
         const EXAMPLE_INPUT_1: &'static str = ex_for_day!($day, 1);
@@ -36,38 +48,124 @@ macro_rules! day_stuff {
     }
 }
 
-const BENCH_SECS: u64 = 5;
-const PROGRESS_TEMPLATE: &str = "{spinner} {wide_msg} [{bar:100.green/cyan}]";
-const PROGRESS_CHARS: &str = "=>-";
-
-macro_rules! bench {
-    ($t:expr) => {{
-        let outer_instant = std::time::Instant::now();
-        let mut times = Vec::<std::time::Duration>::with_capacity(1000);
-        let mut res = None;
-        let style = ProgressStyle::with_template(PROGRESS_TEMPLATE)
-            .unwrap()
-            .progress_chars(PROGRESS_CHARS);
-        let progress = indicatif::ProgressBar::new(BENCH_SECS);
-        progress.set_style(style);
-        let mut i = 0;
-        while outer_instant.elapsed().as_secs() <= BENCH_SECS {
-            let instant = std::time::Instant::now();
-            let r = $t;
-            if res.is_none() {
-                res = Some(r)
+/// An extra example for a part beyond `EXAMPLE_INPUT_1`/`EXAMPLE_INPUT_2`.
+///
+/// Read by the `year!` macro from `examples/day_N/<part>_<name>.txt`,
+/// with the expected answer in `examples/day_N/<part>_<name>.expected`.
+#[derive(Clone, Copy, Debug)]
+pub struct ExampleCase {
+    pub part: usize,
+    /// The file name of the case without its extension, `1_small` say.
+    pub name: &'static str,
+    pub input: &'static str,
+    pub expected: &'static str,
+}
+
+/// The answer to a part, along with how long parsing the input and solving took.
+#[derive(Clone, Debug)]
+pub struct Solution {
+    pub answer: Option<Answer>,
+    /// `None` if the part reused input parsed for a previous part.
+    pub parse_time: Option<Duration>,
+    pub solve_time: Duration,
+    /// Why the part didn't finish, if it didn't.
+    pub failure: Option<Failure>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Failure {
+    /// Parsing or solving panicked with this message.
+    Panic(String),
+    /// Parsing or solving returned this error, see [`InputError`].
+    Error(String),
+    /// The part ran for longer than its timeout.
+    Timeout,
+}
+
+/// How to run parts outside of benchmarks.
+#[derive(Clone, Copy, Debug)]
+pub struct RunConfig {
+    /// How long each part may run for before it's abandoned, `None` to wait forever.
+    ///
+    /// Days can override this with [`Day::TIMEOUT`].
+    pub timeout: Option<Duration>,
+    /// How many days may run at once, `1` runs everything in order on the current thread.
+    pub jobs: usize,
+}
+
+impl Default for RunConfig {
+    fn default() -> Self {
+        Self {
+            timeout: None,
+            jobs: 1,
+        }
+    }
+}
+
+thread_local! {
+    /// Where the panic currently being caught on this thread happened.
+    static PANIC_LOCATION: Cell<Option<String>> = const { Cell::new(None) };
+    static CATCHING: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Replace the panic hook with one that stays quiet for panics caught by [`catch_panic`],
+/// recording their location instead of printing a message and backtrace.
+fn install_panic_hook() {
+    static INSTALL: Once = Once::new();
+    INSTALL.call_once(|| {
+        let default = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if CATCHING.get() {
+                PANIC_LOCATION.set(info.location().map(|l| l.to_string()));
+            } else {
+                default(info);
             }
-            times.push(instant.elapsed());
-            i += 1;
-            progress.set_message(format!("{i} Runs"));
-            progress.set_position(outer_instant.elapsed().as_secs());
-        }
-        (
-            times.iter().sum::<std::time::Duration>() / (times.len() as u32),
-            times.len(),
-            res.unwrap(),
-        )
-    }};
+        }));
+    });
+}
+
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    let message = payload
+        .downcast_ref::<&str>()
+        .map(|s| s.to_string())
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| "Box<dyn Any>".to_string());
+
+    match PANIC_LOCATION.take() {
+        Some(location) => format!("{message} ({location})"),
+        None => message,
+    }
+}
+
+/// Run `f`, catching a panic and returning its message instead of unwinding further.
+pub(crate) fn catch_panic<T>(f: impl FnOnce() -> T) -> Result<T, String> {
+    install_panic_hook();
+    let was_catching = CATCHING.replace(true);
+    let result = panic::catch_unwind(AssertUnwindSafe(f));
+    CATCHING.set(was_catching);
+    result.map_err(panic_message)
+}
+
+/// Whether a part's trial run went well enough to benchmark it, saying why not if it didn't.
+pub(crate) fn benchable(day: usize, part: usize, solution: &Solution) -> bool {
+    match &solution.failure {
+        Some(Failure::Panic(e) | Failure::Error(e)) => {
+            eprintln!("Day {day} Part {part}: FAILED: {e}");
+            false
+        }
+        Some(Failure::Timeout) => {
+            eprintln!(
+                "Day {day} Part {part}: TIMEOUT after {:.2?}",
+                solution.solve_time
+            );
+            false
+        }
+        None if solution.answer.is_none() => {
+            eprintln!("Day {day} Part {part}: Not implemented");
+            false
+        }
+        None => true,
+    }
 }
 
 /// A trait for a day of Advent of Code.
@@ -82,16 +180,29 @@ macro_rules! bench {
 /// Then, any runner can use `run_part` to run a part of the day with a given input or the example input.
 ///
 pub trait Day {
-    type Input: Clone;
+    /// The parsed input, which can borrow from the raw input it's parsed from as `'a`.
+    type Input<'a>;
 
     const DAY: usize = 0;
 
+    /// The puzzle's title, as in "Day 1: Historian Hysteria".
+    const TITLE: &'static str = "";
+
     const EXAMPLE_INPUT_1: &'static str = "";
     const EXAMPLE_INPUT_2: &'static str = "";
 
     const EXPECTED_1: &'static str = "";
     const EXPECTED_2: &'static str = "";
 
+    /// How long each part of this day may run for, overriding [`RunConfig::timeout`].
+    const TIMEOUT: Option<Duration> = None;
+
+    /// Whether this day can run alongside other days.
+    ///
+    /// Days that already parallelize themselves (with rayon, say) should set this to `false`,
+    /// they then run alone so they have every core and their timings aren't skewed.
+    const PARALLEL: bool = true;
+
     fn get_example_input(part: usize) -> &'static str {
         match part {
             1 => Self::EXAMPLE_INPUT_1,
@@ -100,93 +211,248 @@ pub trait Day {
         }
     }
 
-    fn run_part(part: usize, input: Option<&str>) -> Option<String> {
-        let input = input.unwrap_or_else(|| Self::get_example_input(part));
-        let input = Self::parse_input(input);
-        let instant = Instant::now();
-        let solution = match part {
-            1 => Self::part_1(input),
-            2 => Self::part_2(input),
+    fn get_expected(part: usize) -> &'static str {
+        match part {
+            1 => Self::EXPECTED_1,
+            2 => Self::EXPECTED_2,
             _ => panic!("Invalid part number"),
-        };
-        println!(
-            "Day {} Part {}: {} ({:?})",
-            Self::DAY,
-            part,
-            solution.as_ref().unwrap_or(&"Not implemented".to_string()),
-            instant.elapsed()
-        );
-        solution
-    }
-
-    fn bench_part(part: usize, input: Option<&str>) {
-        let input = input.unwrap_or_else(|| Self::get_example_input(part));
-        let (parse_time, sample_size, input) = bench!(Self::parse_input(input));
-        println!(
-            "Day {} Parse Func: {:?} (N = {})",
-            Self::DAY,
-            parse_time,
-            sample_size
-        );
-
-        let (part_time, sample_size, _output) = match part {
-            1 => bench!(Self::part_1(input.clone())),
-            2 => bench!(Self::part_2(input.clone())),
-            _ => panic!("Invalid Part Number"),
-        };
+        }
+    }
+
+    /// Parse an input and solve parts of it, timing both steps separately.
+    ///
+    /// Consecutive parts that run against the same input share a single parse, only the first
+    /// of them reports a parse time. A failed parse is shared too, every part reports its failure.
+    ///
+    /// A panic or error while parsing or solving is caught and reported in the part's [`Solution`].
+    fn run_parts(parts: &[usize], input: Option<&str>) -> Vec<Solution> {
+        let mut solutions = Vec::with_capacity(parts.len());
+        Self::solve_parts(parts, input, |solution| solutions.push(solution));
+        solutions
+    }
 
-        println!(
-            "Day {} Part {}: {:?} (N = {})",
-            Self::DAY,
-            part,
-            part_time,
-            sample_size
-        );
-    }
-
-    fn run_all_parts(extra_indent: &str) {
-        println!(
-            "{extra_indent}Day {day}:",
-            extra_indent = extra_indent,
-            day = Self::DAY
-        );
-        for part in 1..=2 {
-            let part_time = Instant::now();
-            let solution = match part {
-                1 => Self::part_1(Self::parse_input(Self::EXAMPLE_INPUT_1)),
-                2 => Self::part_2(Self::parse_input(Self::EXAMPLE_INPUT_2)),
+    /// Like [`Day::run_parts`], but handing each part's solution to `each` as soon as it's solved.
+    fn solve_parts(parts: &[usize], input: Option<&str>, mut each: impl FnMut(Solution)) {
+        let raws = parts
+            .iter()
+            .map(|&part| match input {
+                Some(input) => Cow::Borrowed(input),
+                None => normalize_input(Self::get_example_input(part)),
+            })
+            .collect::<Vec<_>>();
+        let mut parsed: Option<(&str, Result<Self::Input<'_>, Failure>)> = None;
+
+        for (i, &part) in parts.iter().enumerate() {
+            let raw = &*raws[i];
+
+            let parse_time = match &parsed {
+                Some((prev, _)) if *prev == raw => None,
+                _ => {
+                    let instant = Instant::now();
+                    let result = catch_panic(|| Self::try_parse_input(raw));
+                    let parse_time = Some(instant.elapsed());
+                    let input = match result {
+                        Ok(Ok(input)) => Ok(input),
+                        Ok(Err(e)) => Err(Failure::Error(e.to_string())),
+                        Err(e) => Err(Failure::Panic(e)),
+                    };
+                    parsed = Some((raw, input));
+                    parse_time
+                }
+            };
+
+            let input = match &parsed.as_ref().unwrap().1 {
+                Ok(input) => input,
+                Err(failure) => {
+                    each(Solution {
+                        answer: None,
+                        parse_time,
+                        solve_time: Duration::ZERO,
+                        failure: Some(failure.clone()),
+                    });
+                    continue;
+                }
+            };
+
+            let solve = match part {
+                1 => Self::try_part_1,
+                2 => Self::try_part_2,
                 _ => panic!("Invalid part number"),
             };
-            println!(
-                "{extra_indent}  Part {}: {} ({:?})",
-                part,
-                solution.as_ref().unwrap_or(&"Not implemented".to_string()),
-                part_time.elapsed()
-            );
+            let instant = Instant::now();
+            let result = catch_panic(|| solve(input));
+            let solve_time = instant.elapsed();
+
+            let (answer, failure) = match result {
+                Ok(Ok(answer)) => (answer, None),
+                Ok(Err(e)) => (None, Some(Failure::Error(e.to_string()))),
+                Err(e) => (None, Some(Failure::Panic(e))),
+            };
+
+            each(Solution {
+                answer,
+                parse_time,
+                solve_time,
+                failure,
+            });
         }
     }
 
-    fn parse_input(input: &str) -> Self::Input;
+    /// Like [`Day::run_parts`], but giving up on any part that runs past the configured timeout.
+    ///
+    /// With a timeout, parts run on a worker thread. A part that times out is left running
+    /// in the background and the parts after it continue on a fresh worker. Runners avoid
+    /// this by running parts with a timeout in a child process that can be killed instead,
+    /// see [`crate::enable_child_processes`].
+    fn run_parts_with(parts: &[usize], input: Option<&str>, config: &RunConfig) -> Vec<Solution>
+    where
+        Self: 'static,
+    {
+        let Some(timeout) = Self::TIMEOUT.or(config.timeout) else {
+            return Self::run_parts(parts, input);
+        };
+
+        let mut solutions = Vec::with_capacity(parts.len());
+
+        while solutions.len() < parts.len() {
+            let remaining = parts[solutions.len()..].to_vec();
+            let input = input.map(str::to_string);
+            let (tx, rx) = mpsc::channel();
+
+            thread::Builder::new()
+                .name(format!("day-{}", Self::DAY))
+                .stack_size(WORKER_STACK_SIZE)
+                .spawn(move || {
+                    // Nobody is listening once a part times out, so send errors are ignored
+                    Self::solve_parts(&remaining, input.as_deref(), |solution| {
+                        let _ = tx.send(solution);
+                    });
+                })
+                .expect("Failed to spawn worker thread");
+
+            for _ in solutions.len()..parts.len() {
+                let instant = Instant::now();
+                match rx.recv_timeout(timeout) {
+                    Ok(solution) => solutions.push(solution),
+                    Err(_) => {
+                        solutions.push(Solution {
+                            answer: None,
+                            parse_time: None,
+                            solve_time: instant.elapsed(),
+                            failure: Some(Failure::Timeout),
+                        });
+                        break;
+                    }
+                }
+            }
+        }
+
+        solutions
+    }
+
+    fn run_part(part: usize, input: Option<&str>) -> Solution {
+        Self::run_parts(&[part], input).remove(0)
+    }
+
+    /// Benchmark parsing and solving a part, `None` if the part isn't implemented.
+    ///
+    /// The part is run once first to check it works, then benchmarked with [`Day::bench_checked`].
+    fn bench_part(part: usize, input: Option<&str>, config: &BenchConfig) -> Option<BenchTimes>
+    where
+        Self: 'static,
+    {
+        let run = RunConfig {
+            timeout: config.timeout,
+            ..RunConfig::default()
+        };
+        let solution = Self::run_parts_with(&[part], input, &run).remove(0);
+        benchable(Self::DAY, part, &solution).then(|| Self::bench_checked(part, input, config))
+    }
+
+    /// Benchmark parsing and solving a part that has already been run once without failing.
+    fn bench_checked(part: usize, input: Option<&str>, config: &BenchConfig) -> BenchTimes {
+        let label = if input.is_some() { "" } else { " [example]" };
+        let input = match input {
+            Some(input) => Cow::Borrowed(input),
+            None => normalize_input(Self::get_example_input(part)),
+        };
+        let input = &*input;
+
+        let parse = bench(config, &format!("Day {} Parse", Self::DAY), || {
+            Self::try_parse_input(input)
+        });
+        eprintln!("Day {} Parse Func: {}", Self::DAY, parse);
+
+        let parsed = Self::parse_input(input);
+        let solve_label = format!("Day {} Part {}", Self::DAY, part);
+        let solve = match part {
+            1 => bench(config, &solve_label, || Self::try_part_1(&parsed)),
+            2 => bench(config, &solve_label, || Self::try_part_2(&parsed)),
+            _ => panic!("Invalid Part Number"),
+        };
+        eprintln!("Day {} Part {}{}: {}", Self::DAY, part, label, solve);
+
+        BenchTimes { parse, solve }
+    }
+
+    /// Parse an input, or say where it's malformed.
+    fn try_parse_input(input: &str) -> Result<Self::Input<'_>, InputError>;
+
+    /// Parse an input, panicking with the error from [`Day::try_parse_input`] if it's malformed.
+    fn parse_input(input: &str) -> Self::Input<'_> {
+        Self::try_parse_input(input).unwrap_or_else(|e| panic!("{e}"))
+    }
 
-    fn part_1(_input: Self::Input) -> Option<String> {
+    fn part_1(_input: &Self::Input<'_>) -> Option<Answer> {
         None
     }
-    fn part_2(_input: Self::Input) -> Option<String> {
+    fn part_2(_input: &Self::Input<'_>) -> Option<Answer> {
         None
     }
 
+    /// Solve part 1, or say why it can't be solved. Defaults to [`Day::part_1`].
+    fn try_part_1(input: &Self::Input<'_>) -> Result<Option<Answer>, InputError> {
+        Ok(Self::part_1(input))
+    }
+
+    /// Solve part 2, or say why it can't be solved. Defaults to [`Day::part_2`].
+    fn try_part_2(input: &Self::Input<'_>) -> Result<Option<Answer>, InputError> {
+        Ok(Self::part_2(input))
+    }
+
+    /// Assert that `part` gives `expected` for `input`, skipping parts that aren't implemented.
+    ///
+    /// The `year!` macro marks tests of parts that aren't implemented as ignored, so skips
+    /// here only happen for parts that give no answer despite looking implemented.
+    fn assert_case(part: usize, input: &str, expected: &str) {
+        let input = normalize_input(input);
+        let solution = Self::run_part(part, Some(&input));
+        if let Some(Failure::Panic(e) | Failure::Error(e)) = solution.failure {
+            panic!("{e}");
+        }
+        if let Some(actual) = solution.answer {
+            assert_eq!(actual, expected.trim());
+        }
+    }
+
     fn assert_part_1() {
         let expected = Self::EXPECTED_1;
-        let actual = Self::run_part(1, None);
-        if let Some(actual) = actual {
+        let solution = Self::run_part(1, None);
+        if let Some(Failure::Panic(e) | Failure::Error(e)) = solution.failure {
+            panic!("{e}");
+        }
+        if let Some(actual) = solution.answer {
             assert_eq!(actual, expected);
         }
     }
 
     fn assert_part_2() {
         let expected = Self::EXPECTED_2;
-        let actual = Self::run_part(2, None);
-        if let Some(actual) = actual {
+        let solution = Self::run_part(2, None);
+        if let Some(Failure::Panic(e) | Failure::Error(e)) = solution.failure {
+            panic!("{e}");
+        }
+        if let Some(actual) = solution.answer {
             assert_eq!(actual, expected);
         }
     }
@@ -195,12 +461,14 @@ pub trait Day {
 #[cfg(test)]
 mod tests {
 
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
     use super::*;
 
     struct TestDay;
 
     impl Day for TestDay {
-        type Input = String;
+        type Input<'a> = String;
 
         const EXAMPLE_INPUT_1: &'static str = "Hello, world!";
         const EXAMPLE_INPUT_2: &'static str = "Hello, world!";
@@ -208,34 +476,47 @@ mod tests {
         const EXPECTED_1: &'static str = "Goodbye, world!";
         const EXPECTED_2: &'static str = "Hello, moon!";
 
-        fn parse_input(input: &str) -> String {
-            input.to_string()
+        fn try_parse_input(input: &str) -> Result<String, InputError> {
+            Ok(input.to_string())
         }
 
-        fn part_1(input: String) -> Option<String> {
-            Some(input.replace("Hello", "Goodbye"))
+        fn part_1(input: &String) -> Option<Answer> {
+            Some(input.replace("Hello", "Goodbye").into())
         }
 
-        fn part_2(input: String) -> Option<String> {
-            Some(input.replace("world", "moon"))
+        fn part_2(input: &String) -> Option<Answer> {
+            Some(input.replace("world", "moon").into())
         }
     }
 
     struct TestDay2;
 
     impl Day for TestDay2 {
-        type Input = Vec<String>;
+        type Input<'a> = Vec<&'a str>;
 
         const EXAMPLE_INPUT_1: &'static str = "A\nB\nC";
 
         const EXPECTED_1: &'static str = "A,B,C";
 
-        fn parse_input(input: &str) -> Vec<String> {
-            input.lines().map(|l| l.to_string()).collect::<Vec<_>>()
+        fn try_parse_input(input: &str) -> Result<Vec<&str>, InputError> {
+            Ok(input.lines().collect::<Vec<_>>())
         }
 
-        fn part_1(input: Vec<String>) -> Option<String> {
-            Some(input.join(",").to_string())
+        fn part_1(input: &Vec<&str>) -> Option<Answer> {
+            Some(input.clone().into())
+        }
+    }
+
+    static BAD_PARSES: AtomicUsize = AtomicUsize::new(0);
+
+    struct BadInputDay;
+
+    impl Day for BadInputDay {
+        type Input<'a> = String;
+
+        fn try_parse_input(_input: &str) -> Result<String, InputError> {
+            BAD_PARSES.fetch_add(1, Ordering::Relaxed);
+            Err(InputError::new("bad input"))
         }
     }
 
@@ -251,4 +532,18 @@ mod tests {
         // Should skip
         TestDay2::assert_part_2();
     }
+
+    #[test]
+    fn test_parse_failure_shared() {
+        let solutions = BadInputDay::run_parts(&[1, 2], Some("garbage"));
+        assert_eq!(BAD_PARSES.load(Ordering::Relaxed), 1);
+        for solution in &solutions {
+            assert_eq!(
+                solution.failure,
+                Some(Failure::Error("bad input".to_string()))
+            );
+        }
+        assert!(solutions[0].parse_time.is_some());
+        assert_eq!(solutions[1].parse_time, None);
+    }
 }
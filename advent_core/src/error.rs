@@ -0,0 +1,111 @@
+use std::fmt::{self, Display};
+
+/// An error parsing an input or solving a part, along with where in the input it went wrong.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InputError {
+    pub message: String,
+    /// Where in the input the error is, if known.
+    pub location: Option<Location>,
+}
+
+/// A position in an input, lines and columns start at 1.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Location {
+    pub line: usize,
+    pub column: usize,
+    /// The whole line the error is on.
+    pub text: String,
+    /// How many characters of the line the error covers, at least 1.
+    pub len: usize,
+}
+
+impl InputError {
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+            location: None,
+        }
+    }
+
+    /// Point the error at `span`, which should be a slice of `input` such as the token that
+    /// couldn't be parsed. Spans that aren't slices of `input` are looked for in it instead.
+    pub fn at(self, input: &str, span: &str) -> Self {
+        let start = input.as_ptr() as usize;
+        let offset = (span.as_ptr() as usize)
+            .checked_sub(start)
+            .filter(|offset| offset + span.len() <= input.len())
+            .or_else(|| input.find(span));
+
+        match offset {
+            Some(offset) => self.at_offset(input, offset, span.chars().count()),
+            None => self,
+        }
+    }
+
+    /// Point the error at `len` characters from byte `offset` of `input`.
+    pub fn at_offset(mut self, input: &str, offset: usize, len: usize) -> Self {
+        let line_start = input[..offset].rfind('\n').map_or(0, |i| i + 1);
+        let line_end = input[offset..]
+            .find('\n')
+            .map_or(input.len(), |i| offset + i);
+        let text = &input[line_start..line_end];
+        let column = input[line_start..offset].chars().count() + 1;
+
+        self.location = Some(Location {
+            line: input[..line_start].matches('\n').count() + 1,
+            column,
+            text: text.to_string(),
+            len: len.max(1),
+        });
+        self
+    }
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Some(loc) = &self.location else {
+            return write!(f, "{}", self.message);
+        };
+
+        let gutter = " ".repeat(loc.line.to_string().len());
+        writeln!(
+            f,
+            "line {}, column {}: {}",
+            loc.line, loc.column, self.message
+        )?;
+        writeln!(f, "{gutter} |")?;
+        writeln!(f, "{} | {}", loc.line, loc.text)?;
+        write!(
+            f,
+            "{gutter} | {}{}",
+            " ".repeat(loc.column - 1),
+            "^".repeat(loc.len)
+        )
+    }
+}
+
+impl std::error::Error for InputError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_input_error_at() {
+        let input = "Button A: X+94, Y+34\nButton B: X+2z, Y+67";
+        let offset = input.find("2z").unwrap();
+        let bad = &input[offset..offset + 2];
+
+        let error = InputError::new("expected a number").at(input, bad);
+        let location = error.location.clone().unwrap();
+        assert_eq!((location.line, location.column, location.len), (2, 13, 2));
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 13: expected a number\n  |\n2 | Button B: X+2z, Y+67\n  |             ^^"
+        );
+
+        let error = InputError::new("missing").at(input, "Y+67");
+        assert_eq!(error.location.unwrap().line, 2);
+        assert_eq!(InputError::new("bad").at(input, "nope").to_string(), "bad");
+    }
+}
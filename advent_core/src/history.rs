@@ -0,0 +1,338 @@
+use std::collections::BTreeMap;
+use std::fs::{self, OpenOptions};
+use std::io::{self, ErrorKind, Write};
+use std::path::PathBuf;
+use std::process::Command;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+
+use crate::bench::{BenchResult, Stats};
+use crate::report::InputKind;
+
+/// Changes smaller than this fraction of the baseline mean are never flagged.
+const NOISE_THRESHOLD: f64 = 0.05;
+
+/// Welch's t statistic above which a change is considered significant (~95% confidence).
+const T_CRITICAL: f64 = 2.0;
+
+/// One invocation of `advent bench`.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct BenchRun {
+    /// Seconds since the Unix epoch.
+    pub timestamp: u64,
+    /// Short git revision, suffixed with `-dirty` if there were uncommitted changes.
+    pub revision: Option<String>,
+    pub results: Vec<BenchResult>,
+}
+
+impl BenchRun {
+    pub fn new(results: Vec<BenchResult>) -> Self {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or_default();
+
+        Self {
+            timestamp,
+            revision: git_revision(),
+            results,
+        }
+    }
+}
+
+fn git_revision() -> Option<String> {
+    let git = |args: &[&str]| {
+        Command::new("git")
+            .args(args)
+            .output()
+            .ok()
+            .filter(|o| o.status.success())
+            .map(|o| String::from_utf8_lossy(&o.stdout).trim().to_string())
+    };
+
+    let revision = git(&["rev-parse", "--short", "HEAD"])?;
+    let dirty = git(&["status", "--porcelain"]).is_some_and(|s| !s.is_empty());
+
+    Some(if dirty {
+        format!("{revision}-dirty")
+    } else {
+        revision
+    })
+}
+
+/// Where bench runs are kept, an append-only `history.jsonl` and named baselines.
+#[derive(Clone, Debug)]
+pub struct BenchHistory {
+    dir: PathBuf,
+}
+
+impl Default for BenchHistory {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_DIR)
+    }
+}
+
+impl BenchHistory {
+    /// Kept out of `target/` so `cargo clean` doesn't throw away saved baselines.
+    pub const DEFAULT_DIR: &str = "bench";
+
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    fn baseline_path(&self, name: &str) -> PathBuf {
+        self.dir.join("baselines").join(format!("{name}.json"))
+    }
+
+    /// Append a run as a single line to the history file.
+    pub fn append(&self, run: &BenchRun) -> io::Result<()> {
+        fs::create_dir_all(&self.dir)?;
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(self.dir.join("history.jsonl"))?;
+        writeln!(file, "{}", serde_json::to_string(run)?)
+    }
+
+    pub fn save_baseline(&self, name: &str, run: &BenchRun) -> io::Result<()> {
+        let path = self.baseline_path(name);
+        fs::create_dir_all(path.parent().unwrap())?;
+        fs::write(path, serde_json::to_string_pretty(run)?)
+    }
+
+    pub fn load_baseline(&self, name: &str) -> io::Result<BenchRun> {
+        let path = self.baseline_path(name);
+        let contents = fs::read_to_string(&path).map_err(|e| {
+            if e.kind() == ErrorKind::NotFound {
+                io::Error::new(e.kind(), format!("No baseline named `{name}`"))
+            } else {
+                e
+            }
+        })?;
+        Ok(serde_json::from_str(&contents)?)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Change {
+    Improved,
+    Regressed,
+    NoChange,
+}
+
+impl Change {
+    /// Compare two measurements with Welch's t-test, ignoring changes within the noise threshold.
+    pub fn between(baseline: &Stats, current: &Stats) -> Self {
+        let mean = |s: &Stats| s.mean.as_secs_f64();
+        let var = |s: &Stats| s.std_dev.as_secs_f64().powi(2) / s.samples.max(1) as f64;
+
+        let diff = mean(current) - mean(baseline);
+        let relative = diff / mean(baseline).max(f64::EPSILON);
+        let err = (var(baseline) + var(current)).sqrt();
+        let t = if err > 0.0 {
+            diff / err
+        } else {
+            f64::INFINITY * diff.signum()
+        };
+
+        if relative.abs() < NOISE_THRESHOLD || t.abs() < T_CRITICAL {
+            Self::NoChange
+        } else if diff > 0.0 {
+            Self::Regressed
+        } else {
+            Self::Improved
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Improved => "improved",
+            Self::Regressed => "REGRESSED",
+            Self::NoChange => "",
+        }
+    }
+}
+
+/// How the mean time of one step of a part changed from the baseline.
+#[derive(Clone, Copy, Debug, Serialize)]
+pub struct StepChange {
+    #[serde(rename = "was_ns", with = "crate::bench::nanos")]
+    pub was: Duration,
+    #[serde(rename = "now_ns", with = "crate::bench::nanos")]
+    pub now: Duration,
+    /// The change in mean time as a percentage of the baseline.
+    pub delta_percent: f64,
+    pub change: Change,
+}
+
+impl StepChange {
+    fn new(was: &Stats, now: &Stats) -> Self {
+        let was_secs = was.mean.as_secs_f64();
+        Self {
+            was: was.mean,
+            now: now.mean,
+            delta_percent: (now.mean.as_secs_f64() - was_secs) / was_secs.max(f64::EPSILON) * 100.0,
+            change: Change::between(was, now),
+        }
+    }
+}
+
+/// How a part benched in both runs changed.
+#[derive(Clone, Debug, Serialize)]
+pub struct PartComparison {
+    pub year: usize,
+    pub day: usize,
+    pub part: usize,
+    pub input: InputKind,
+    pub parse: StepChange,
+    pub solve: StepChange,
+    /// Regressed if either step regressed, otherwise improved if either improved.
+    pub change: Change,
+}
+
+/// A bench run compared to a saved baseline.
+#[derive(Clone, Debug, Serialize)]
+pub struct Comparison {
+    pub baseline: String,
+    /// The revision the baseline was benched at.
+    pub revision: Option<String>,
+    pub parts: Vec<PartComparison>,
+}
+
+impl Comparison {
+    /// Compare every part benched in both runs against the same kind of input.
+    pub fn new(name: &str, baseline: &BenchRun, current: &BenchRun) -> Self {
+        let baseline_results = baseline
+            .results
+            .iter()
+            .map(|r| ((r.year, r.day, r.part, r.input), r))
+            .collect::<BTreeMap<_, _>>();
+
+        let parts = current
+            .results
+            .iter()
+            .filter_map(|r| {
+                let base = baseline_results.get(&(r.year, r.day, r.part, r.input))?;
+                let parse = StepChange::new(&base.times.parse, &r.times.parse);
+                let solve = StepChange::new(&base.times.solve, &r.times.solve);
+                let changes = [parse.change, solve.change];
+                let change = if changes.contains(&Change::Regressed) {
+                    Change::Regressed
+                } else if changes.contains(&Change::Improved) {
+                    Change::Improved
+                } else {
+                    Change::NoChange
+                };
+                Some(PartComparison {
+                    year: r.year,
+                    day: r.day,
+                    part: r.part,
+                    input: r.input,
+                    parse,
+                    solve,
+                    change,
+                })
+            })
+            .collect();
+
+        Self {
+            baseline: name.to_string(),
+            revision: baseline.revision.clone(),
+            parts,
+        }
+    }
+
+    /// How many parts regressed significantly.
+    pub fn regressions(&self) -> usize {
+        self.parts
+            .iter()
+            .filter(|p| p.change == Change::Regressed)
+            .count()
+    }
+
+    /// Find how a part changed, if it was benched in both runs.
+    pub fn get(&self, result: &BenchResult) -> Option<&PartComparison> {
+        self.parts.iter().find(|p| {
+            (p.year, p.day, p.part, p.input) == (result.year, result.day, result.part, result.input)
+        })
+    }
+}
+
+/// Print the change in solve and parse time of every part benched in both runs.
+pub fn print_comparison(comparison: &Comparison) {
+    println!();
+    println!(
+        "Compared to baseline `{}` ({})",
+        comparison.baseline,
+        comparison.revision.as_deref().unwrap_or("unknown revision")
+    );
+    println!(
+        "{:<4}  {:>3}  {:>4}  {:>12}  {:>12}  {:>8}  {:>12}  {:>12}  {:>8}  Change",
+        "Year", "Day", "Part", "Parse Was", "Parse Now", "Δ", "Solve Was", "Solve Now", "Δ",
+    );
+
+    for p in comparison.parts.iter() {
+        println!(
+            "{:<4}  {:>3}  {:>4}  {:>12}  {:>12}  {:>8}  {:>12}  {:>12}  {:>8}  {}",
+            p.year,
+            p.day,
+            p.part,
+            format!("{:.2?}", p.parse.was),
+            format!("{:.2?}", p.parse.now),
+            format!("{:+.1}%", p.parse.delta_percent),
+            format!("{:.2?}", p.solve.was),
+            format!("{:.2?}", p.solve.now),
+            format!("{:+.1}%", p.solve.delta_percent),
+            p.change.as_str()
+        );
+    }
+
+    let regressions = comparison.regressions();
+    if regressions > 0 {
+        println!("\n{regressions} part(s) regressed significantly");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stats(mean_us: u64, std_dev_us: u64) -> Stats {
+        let mean = Duration::from_micros(mean_us);
+        Stats {
+            samples: 100,
+            iterations: 100,
+            mean,
+            median: mean,
+            min: mean,
+            max: mean,
+            std_dev: Duration::from_micros(std_dev_us),
+            p95: mean,
+            p99: mean,
+        }
+    }
+
+    #[test]
+    fn test_change() {
+        assert_eq!(
+            Change::between(&stats(100, 1), &stats(150, 1)),
+            Change::Regressed
+        );
+        assert_eq!(
+            Change::between(&stats(150, 1), &stats(100, 1)),
+            Change::Improved
+        );
+        // Within the noise threshold
+        assert_eq!(
+            Change::between(&stats(100, 0), &stats(104, 0)),
+            Change::NoChange
+        );
+        // Too noisy to tell
+        assert_eq!(
+            Change::between(&stats(100, 200), &stats(120, 200)),
+            Change::NoChange
+        );
+    }
+}
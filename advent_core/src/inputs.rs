@@ -0,0 +1,172 @@
+use std::borrow::Cow;
+use std::fs;
+use std::io::{self, ErrorKind, Read};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use crate::answers::Answers;
+
+/// Normalize puzzle input so it parses the same however it was saved or passed in.
+///
+/// Strips a leading byte order mark, converts CRLF line endings to LF, and trims trailing
+/// whitespace, including the final newline. Leading whitespace is kept, some puzzles need it.
+pub fn normalize_input(input: &str) -> Cow<'_, str> {
+    let input = input.strip_prefix('\u{feff}').unwrap_or(input).trim_end();
+    if input.contains('\r') {
+        Cow::Owned(input.replace("\r\n", "\n"))
+    } else {
+        Cow::Borrowed(input)
+    }
+}
+
+/// Where to get a puzzle input from.
+///
+/// Parsed from `-` for stdin, `@stored` for the stored input, `@example` for each part's
+/// own example, `@example:N` for part N's example, `@text:...` for literal input,
+/// and anything else as a path to a file.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum InputSource {
+    File(PathBuf),
+    Stdin,
+    Literal(String),
+    Stored,
+    /// The example of the given part, or each part's own example if `None`.
+    Example(Option<usize>),
+}
+
+impl FromStr for InputSource {
+    type Err = String;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input {
+            "-" => Ok(Self::Stdin),
+            "@stored" => Ok(Self::Stored),
+            "@example" => Ok(Self::Example(None)),
+            _ => {
+                if let Some(text) = input.strip_prefix("@text:") {
+                    Ok(Self::Literal(text.to_string()))
+                } else if let Some(part) = input.strip_prefix("@example:") {
+                    match part.parse() {
+                        Ok(part @ 1..=2) => Ok(Self::Example(Some(part))),
+                        _ => Err(format!("Invalid example `{part}`, expected 1 or 2")),
+                    }
+                } else if input.starts_with('@') {
+                    Err(format!(
+                        "Unknown input `{input}`, expected @stored, @example[:N], or @text:..."
+                    ))
+                } else {
+                    Ok(Self::File(input.into()))
+                }
+            }
+        }
+    }
+}
+
+impl InputSource {
+    /// Whether this is the same input for every day, rather than one looked up for each day.
+    pub fn is_fixed(&self) -> bool {
+        matches!(self, Self::File(_) | Self::Stdin | Self::Literal(_))
+    }
+
+    /// Read a file or stdin into a [`InputSource::Literal`], normalizing it along with
+    /// literal input. Stored and example inputs are read when they're used.
+    ///
+    /// Stdin can only be read once, so sources that'll be used for several days should be loaded first.
+    pub fn load(self) -> io::Result<Self> {
+        let text = match self {
+            Self::File(path) => fs::read_to_string(&path)
+                .map_err(|e| io::Error::new(e.kind(), format!("{}: {e}", path.display())))?,
+            Self::Stdin => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input)?;
+                input
+            }
+            Self::Literal(text) => text,
+            source => return Ok(source),
+        };
+        Ok(Self::Literal(normalize_input(&text).into_owned()))
+    }
+}
+
+/// A directory of real puzzle inputs, laid out as `<root>/<year>/day_<day>.txt`.
+///
+/// Inputs are personal to each Advent of Code account, so the default `inputs/`
+/// directory is ignored by git.
+#[derive(Clone, Debug)]
+pub struct InputStore {
+    root: PathBuf,
+}
+
+impl Default for InputStore {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_DIR)
+    }
+}
+
+impl InputStore {
+    pub const DEFAULT_DIR: &str = "inputs";
+
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    pub fn year_dir(&self, year: usize) -> PathBuf {
+        self.root.join(year.to_string())
+    }
+
+    pub fn path(&self, year: usize, day: usize) -> PathBuf {
+        self.year_dir(year).join(format!("day_{day}.txt"))
+    }
+
+    /// Read the stored input for a day, `None` if it hasn't been saved yet.
+    pub fn get(&self, year: usize, day: usize) -> Option<String> {
+        let path = self.path(year, day);
+        match std::fs::read_to_string(&path) {
+            Ok(input) => Some(normalize_input(&input).into_owned()),
+            Err(e) if e.kind() == ErrorKind::NotFound => None,
+            Err(e) => {
+                eprintln!("Failed to read {}: {e}", path.display());
+                None
+            }
+        }
+    }
+
+    /// Load the accepted answers recorded for a year.
+    pub fn answers(&self, year: usize) -> io::Result<Answers> {
+        Answers::load(self.year_dir(year).join(Answers::FILE_NAME))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_input() {
+        assert_eq!(normalize_input("a\r\nb\r\n\r\nc\r\n"), "a\nb\n\nc");
+        assert_eq!(normalize_input("\u{feff}  a\nb  \n\n"), "  a\nb");
+        assert!(matches!(normalize_input("a\nb"), Cow::Borrowed("a\nb")));
+    }
+
+    #[test]
+    fn test_input_source_parse() {
+        assert_eq!("-".parse(), Ok(InputSource::Stdin));
+        assert_eq!("@stored".parse(), Ok(InputSource::Stored));
+        assert_eq!("@example".parse(), Ok(InputSource::Example(None)));
+        assert_eq!("@example:2".parse(), Ok(InputSource::Example(Some(2))));
+        assert_eq!(
+            "@text:1 2".parse(),
+            Ok(InputSource::Literal("1 2".to_string()))
+        );
+        assert_eq!(
+            "day_1.txt".parse(),
+            Ok(InputSource::File("day_1.txt".into()))
+        );
+        assert!("@example:3".parse::<InputSource>().is_err());
+        assert!("@nope".parse::<InputSource>().is_err());
+    }
+}
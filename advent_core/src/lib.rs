@@ -1,11 +1,52 @@
+mod answer;
+mod answers;
+mod bench;
 mod bootstrap;
+mod child;
 mod day;
+mod error;
+mod history;
+mod inputs;
+mod parallel;
 mod parser;
+mod registry;
+mod report;
+mod status;
+mod verify;
+mod watch;
 mod year;
 
+/// The most days any year has, each year's own count is [`Year::DAYS`].
 pub const MAX_DAY: usize = 25;
 
-pub use bootstrap::make_year;
-pub use day::Day;
-pub use parser::{get_dp_and_input, get_ydp_and_input, Selection, DP, YDP};
+/// How many days a new year is scaffolded with, 25 until 2025 cut Advent of Code down to 12.
+pub fn default_days(year: usize) -> usize {
+    if year >= 2025 { 12 } else { MAX_DAY }
+}
+
+pub use answer::Answer;
+pub use answers::Answers;
+pub use bench::{
+    BenchConfig, BenchResult, BenchTimes, Stats, bench, bench_with_setup, print_bench_report,
+};
+pub use bootstrap::{FileAction, FileChange, NewYearConfig, make_year, print_year_changes};
+pub use child::{CHILD_COMMAND, enable_child_processes, serve_child};
+pub use day::{Day, ExampleCase, Failure, RunConfig, Solution};
+pub use error::{InputError, Location};
+pub use history::{
+    BenchHistory, BenchRun, Change, Comparison, PartComparison, StepChange, print_comparison,
+};
+pub use inputs::{InputSource, InputStore, normalize_input};
+pub use parallel::map_ordered;
+pub use parser::{
+    DP, Selection, YDP, expand_inputs, get_dp_and_input, is_input_pattern, parse_duration,
+};
+pub use registry::{DayEntry, DayListing};
+pub use report::{
+    InputKind, OutputFormat, PartResult, Status, print_bench, print_days, print_inputs,
+    print_results, print_status, print_verify,
+};
+pub use status::{PartStatus, Progress, part_statuses};
+pub use verify::{check_answer, print_verify_table};
+pub use watch::watch;
 pub use year::Year;
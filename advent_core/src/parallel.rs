@@ -0,0 +1,109 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Condvar, Mutex, RwLock};
+use std::thread;
+
+/// Days run off the main thread get the 8 MiB of stack Linux gives the main thread by default,
+/// rather than the 2 MiB other threads get, so recursive solutions behave the same either way.
+pub(crate) const WORKER_STACK_SIZE: usize = 8 * 1024 * 1024;
+
+/// Held for reading by every day running alongside others, and for writing by days that
+/// opt out with [`crate::Day::PARALLEL`] so they get the whole machine to themselves.
+static EXCLUSIVE: RwLock<()> = RwLock::new(());
+
+/// How many days are running right now, shared by every year so running years
+/// concurrently doesn't multiply the number of jobs.
+static RUNNING: Mutex<usize> = Mutex::new(0);
+static SLOT_FREED: Condvar = Condvar::new();
+
+struct Slot;
+
+impl Slot {
+    fn acquire(jobs: usize) -> Self {
+        let mut running = RUNNING.lock().unwrap();
+        while *running >= jobs {
+            running = SLOT_FREED.wait(running).unwrap();
+        }
+        *running += 1;
+        Self
+    }
+}
+
+impl Drop for Slot {
+    fn drop(&mut self) {
+        *RUNNING.lock().unwrap() -= 1;
+        SLOT_FREED.notify_all();
+    }
+}
+
+/// Run a day once one of `jobs` slots is free, waiting for every other day to finish
+/// first if it isn't `parallel`.
+pub(crate) fn run_limited<R>(jobs: usize, parallel: bool, f: impl FnOnce() -> R) -> R {
+    if jobs <= 1 {
+        return f();
+    }
+
+    let _slot = Slot::acquire(jobs);
+    if parallel {
+        let _shared = EXCLUSIVE.read().unwrap_or_else(|e| e.into_inner());
+        f()
+    } else {
+        let _exclusive = EXCLUSIVE.write().unwrap_or_else(|e| e.into_inner());
+        f()
+    }
+}
+
+/// Map `f` over `items` on up to `jobs` threads, returning the results in the same order
+/// as the items they came from.
+pub fn map_ordered<T, R>(items: Vec<T>, jobs: usize, f: impl Fn(T) -> R + Sync) -> Vec<R>
+where
+    T: Send,
+    R: Send,
+{
+    if jobs <= 1 || items.len() <= 1 {
+        return items.into_iter().map(f).collect();
+    }
+
+    let len = items.len();
+    let items = items
+        .into_iter()
+        .map(|i| Mutex::new(Some(i)))
+        .collect::<Vec<_>>();
+    let results = (0..len).map(|_| Mutex::new(None)).collect::<Vec<_>>();
+    let next = AtomicUsize::new(0);
+
+    thread::scope(|s| {
+        for _ in 0..jobs.min(len) {
+            thread::Builder::new()
+                .stack_size(WORKER_STACK_SIZE)
+                .spawn_scoped(s, || {
+                    loop {
+                        let i = next.fetch_add(1, Ordering::Relaxed);
+                        if i >= len {
+                            break;
+                        }
+                        let item = items[i].lock().unwrap().take().unwrap();
+                        *results[i].lock().unwrap() = Some(f(item));
+                    }
+                })
+                .expect("Failed to spawn worker thread");
+        }
+    });
+
+    results
+        .into_iter()
+        .map(|r| r.into_inner().unwrap().unwrap())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_map_ordered() {
+        let items = (0..100).collect::<Vec<_>>();
+        let expected = items.iter().map(|i| i * 2).collect::<Vec<_>>();
+        assert_eq!(map_ordered(items.clone(), 1, |i| i * 2), expected);
+        assert_eq!(map_ordered(items, 8, |i| i * 2), expected);
+    }
+}
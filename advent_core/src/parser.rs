@@ -1,20 +1,79 @@
 use std::env::args;
-use std::fs;
-use std::io::{stdin, Read};
+use std::str::FromStr;
+use std::time::Duration;
 
-#[derive(Clone, Debug)]
+use crate::inputs::InputSource;
+
+/// A selection of years, days, or parts.
+///
+/// Parsed from `*`, a single number (`5`), an inclusive range (`1-5`),
+/// an open-ended range (`10-` or `-3`), or a comma separated list of any of these (`1-5,9`).
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub enum Selection {
     All,
-    Single(usize), // TODO: Add range maybe?
+    Single(usize),
+    Range(Option<usize>, Option<usize>),
+    List(Vec<Selection>),
 }
 
 impl Selection {
-    fn parse(input: &str) -> Self {
+    pub fn contains(&self, n: usize) -> bool {
+        match self {
+            Self::All => true,
+            Self::Single(s) => *s == n,
+            Self::Range(start, end) => {
+                start.is_none_or(|start| start <= n) && end.is_none_or(|end| n <= end)
+            }
+            Self::List(list) => list.iter().any(|s| s.contains(n)),
+        }
+    }
+
+    /// Filter `candidates` down to the ones in this selection, preserving order.
+    pub fn select(
+        &self,
+        candidates: impl IntoIterator<Item = usize>,
+    ) -> impl Iterator<Item = usize> {
+        candidates.into_iter().filter(|n| self.contains(*n))
+    }
+
+    fn parse_item(input: &str) -> Result<Self, String> {
+        let parse_num = |n: &str| {
+            n.parse::<usize>().map_err(|_| {
+                format!("Invalid selector `{input}`, expected a number, range, or `*`")
+            })
+        };
+
         if input == "*" {
-            Self::All
+            Ok(Self::All)
+        } else if let Some((start, end)) = input.split_once('-') {
+            let start = (!start.is_empty()).then(|| parse_num(start)).transpose()?;
+            let end = (!end.is_empty()).then(|| parse_num(end)).transpose()?;
+            match (start, end) {
+                (None, None) => Err(format!("Range `{input}` needs a start or an end")),
+                (Some(start), Some(end)) if start > end => {
+                    Err(format!("Range `{input}` ends before it starts"))
+                }
+                (start, end) => Ok(Self::Range(start, end)),
+            }
         } else {
-            let input = input.parse::<usize>().unwrap();
-            Self::Single(input)
+            parse_num(input).map(Self::Single)
+        }
+    }
+}
+
+impl FromStr for Selection {
+    type Err = String;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let mut items = input
+            .split(',')
+            .map(|item| Self::parse_item(item.trim()))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        if items.len() == 1 {
+            Ok(items.remove(0))
+        } else {
+            Ok(Self::List(items))
         }
     }
 }
@@ -30,14 +89,25 @@ const DP_ALL: DP = DP {
     part: Selection::All,
 };
 
-impl DP {
-    fn parse(input: &str) -> Self {
-        let mut split = input.split(':');
+impl FromStr for DP {
+    type Err = String;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let mut split = input.trim().split(':');
 
-        let day = split.next().map(Selection::parse).unwrap_or(Selection::All);
-        let part = split.next().map(Selection::parse).unwrap_or(Selection::All);
+        let day = split.next().map(str::parse).transpose()?;
+        let part = split.next().map(str::parse).transpose()?;
 
-        Self { day, part }
+        if split.next().is_some() {
+            return Err(format!(
+                "Too many parts in `{input}`, expected `day[:part]`"
+            ));
+        }
+
+        Ok(Self {
+            day: day.unwrap_or(Selection::All),
+            part: part.unwrap_or(Selection::All),
+        })
     }
 }
 
@@ -48,17 +118,31 @@ pub struct YDP {
     pub part: Selection,
 }
 
-impl YDP {
-    fn parse(input: &str) -> Self {
-        let mut split = input.split(':');
+impl FromStr for YDP {
+    type Err = String;
 
-        let year = split.next().map(Selection::parse).unwrap_or(Selection::All);
-        let day = split.next().map(Selection::parse).unwrap_or(Selection::All);
-        let part = split.next().map(Selection::parse).unwrap_or(Selection::All);
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let mut split = input.trim().split(':');
 
-        Self { year, day, part }
+        let year = split.next().map(str::parse).transpose()?;
+        let day = split.next().map(str::parse).transpose()?;
+        let part = split.next().map(str::parse).transpose()?;
+
+        if split.next().is_some() {
+            return Err(format!(
+                "Too many parts in `{input}`, expected `year[:day[:part]]`"
+            ));
+        }
+
+        Ok(Self {
+            year: year.unwrap_or(Selection::All),
+            day: day.unwrap_or(Selection::All),
+            part: part.unwrap_or(Selection::All),
+        })
     }
+}
 
+impl YDP {
     pub fn to_dp(&self) -> DP {
         DP {
             day: self.day.clone(),
@@ -67,46 +151,154 @@ impl YDP {
     }
 }
 
-pub fn get_dp_and_input() -> (DP, Option<String>) {
-    let mut args = args().skip(1);
+/// Parse a duration like `500ms`, `1.5s`, or `2m`, a bare number is taken as seconds.
+pub fn parse_duration(input: &str) -> Result<Duration, String> {
+    let input = input.trim();
+    let split = input
+        .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+        .unwrap_or(input.len());
+    let (num, unit) = input.split_at(split);
 
-    let dp = args.next().map(|s| DP::parse(s.trim())).unwrap_or(DP_ALL);
+    let num = num
+        .parse::<f64>()
+        .map_err(|_| format!("Invalid duration `{input}`, expected something like `1.5s`"))?;
+    let secs = match unit.trim() {
+        "ms" => num / 1000.0,
+        "" | "s" => num,
+        "m" => num * 60.0,
+        "h" => num * 3600.0,
+        unit => {
+            return Err(format!(
+                "Unknown duration unit `{unit}`, expected ms, s, m, or h"
+            ));
+        }
+    };
 
-    let input = args.next().map(|s| s.trim().to_string()).map(|i| {
-        if i == "-" {
-            let mut input = String::new();
-            stdin()
-                .read_to_string(&mut input)
-                .expect("Failed to read input");
-            input.trim().to_string()
-        } else {
-            i
+    Duration::try_from_secs_f64(secs).map_err(|_| format!("Duration `{input}` is out of range"))
+}
+
+/// Whether an input is a glob pattern to expand rather than a single path or source.
+pub fn is_input_pattern(input: &str) -> bool {
+    input != "-" && !input.starts_with('@') && input.contains(['*', '?', '['])
+}
+
+/// Expand any glob patterns in a list of inputs, keeping plain paths and other sources as they are.
+///
+/// Matches of each pattern are sorted, a pattern that matches nothing is an error.
+pub fn expand_inputs(patterns: &[String]) -> Result<Vec<String>, String> {
+    let mut inputs = vec![];
+    for pattern in patterns {
+        if !is_input_pattern(pattern) {
+            inputs.push(pattern.clone());
+            continue;
         }
+
+        let mut matches = glob::glob(pattern)
+            .map_err(|e| format!("Invalid pattern `{pattern}`: {e}"))?
+            .map(|path| {
+                path.map(|p| p.display().to_string())
+                    .map_err(|e| e.to_string())
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        if matches.is_empty() {
+            return Err(format!("No inputs match `{pattern}`"));
+        }
+
+        matches.sort();
+        inputs.extend(matches);
+    }
+    Ok(inputs)
+}
+
+/// Parse the arguments of a year's runner, a [`DP`] followed by an optional [`InputSource`].
+pub fn get_dp_and_input() -> (DP, Option<InputSource>) {
+    let mut args = args().skip(1);
+
+    let dp = args
+        .next()
+        .map(|s| {
+            s.parse::<DP>().unwrap_or_else(|e| {
+                eprintln!("{e}");
+                std::process::exit(2);
+            })
+        })
+        .unwrap_or(DP_ALL);
+
+    let input = args.next().map(|s| {
+        s.parse::<InputSource>()
+            .and_then(|source| {
+                source
+                    .load()
+                    .map_err(|e| format!("Failed to read input: {e}"))
+            })
+            .unwrap_or_else(|e| {
+                eprintln!("{e}");
+                std::process::exit(2);
+            })
     });
 
     (dp, input)
 }
 
-pub fn get_ydp_and_input(args: Vec<String>) -> (YDP, Option<String>) {
-    let mut args = args.into_iter();
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    let ydp = args.next().map(|s| YDP::parse(s.trim())).unwrap_or(YDP {
-        year: Selection::All,
-        day: Selection::All,
-        part: Selection::All,
-    });
+    #[test]
+    fn test_selection_parse() {
+        assert_eq!("*".parse(), Ok(Selection::All));
+        assert_eq!("7".parse(), Ok(Selection::Single(7)));
+        assert_eq!("1-5".parse(), Ok(Selection::Range(Some(1), Some(5))));
+        assert_eq!("10-".parse(), Ok(Selection::Range(Some(10), None)));
+        assert_eq!("-3".parse(), Ok(Selection::Range(None, Some(3))));
+        assert_eq!(
+            "1-5,9".parse(),
+            Ok(Selection::List(vec![
+                Selection::Range(Some(1), Some(5)),
+                Selection::Single(9)
+            ]))
+        );
+        assert!("x".parse::<Selection>().is_err());
+        assert!("5-1".parse::<Selection>().is_err());
+        assert!("-".parse::<Selection>().is_err());
+        assert!("1,,2".parse::<Selection>().is_err());
+    }
 
-    let input = args.next().map(|s| s.trim().to_string()).map(|i| {
-        if i == "-" {
-            let mut input = String::new();
-            stdin()
-                .read_to_string(&mut input)
-                .expect("Failed to read input");
-            input.trim().to_string()
-        } else {
-            fs::read_to_string(i).expect("Failed to read input file")
-        }
-    });
+    #[test]
+    fn test_selection_select() {
+        let sel = "-2,10-12,20-".parse::<Selection>().unwrap();
+        assert_eq!(
+            sel.select(1..=25).collect::<Vec<_>>(),
+            vec![1, 2, 10, 11, 12, 20, 21, 22, 23, 24, 25]
+        );
+        assert_eq!(Selection::All.select(1..=2).collect::<Vec<_>>(), vec![1, 2]);
+    }
 
-    (ydp, input)
+    #[test]
+    fn test_parse_duration() {
+        assert_eq!(parse_duration("500ms"), Ok(Duration::from_millis(500)));
+        assert_eq!(parse_duration("1.5s"), Ok(Duration::from_millis(1500)));
+        assert_eq!(parse_duration("2"), Ok(Duration::from_secs(2)));
+        assert_eq!(parse_duration("2m"), Ok(Duration::from_secs(120)));
+        assert!(parse_duration("fast").is_err());
+        assert!(parse_duration("5y").is_err());
+        assert!(parse_duration("-1s").is_err());
+        assert!(parse_duration("99999999999999999999h").is_err());
+    }
+
+    #[test]
+    fn test_ydp_parse() {
+        let ydp = "2024:1-5,9:*".parse::<YDP>().unwrap();
+        assert_eq!(ydp.year, Selection::Single(2024));
+        assert_eq!(
+            ydp.day,
+            Selection::List(vec![
+                Selection::Range(Some(1), Some(5)),
+                Selection::Single(9)
+            ])
+        );
+        assert_eq!(ydp.part, Selection::All);
+        assert!("2024:1:2:3".parse::<YDP>().is_err());
+    }
 }
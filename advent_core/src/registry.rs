@@ -0,0 +1,115 @@
+use std::time::Duration;
+
+use serde::Serialize;
+
+use crate::bench::{BenchConfig, BenchTimes};
+use crate::day::{Day, ExampleCase, RunConfig, Solution};
+
+type SolveEach = fn(&[usize], Option<&str>, &mut dyn FnMut(Solution));
+
+/// Everything a runner needs to know about a day, without knowing the type of its input.
+///
+/// Each year has a static table of these, see [`crate::Year::ENTRIES`], which `year!` builds
+/// from the year's days.
+#[derive(Clone, Copy)]
+pub struct DayEntry {
+    pub day: usize,
+    pub title: &'static str,
+    /// See [`Day::PARALLEL`].
+    pub parallel: bool,
+    /// See [`Day::TIMEOUT`].
+    pub timeout: Option<Duration>,
+    pub example_inputs: [&'static str; 2],
+    pub expected: [&'static str; 2],
+    pub examples: &'static [ExampleCase],
+    /// Parse an input and solve parts of it, see [`Day::run_parts_with`].
+    pub solve: fn(&[usize], Option<&str>, &RunConfig) -> Vec<Solution>,
+    /// Like `solve`, but handing each part's solution over as soon as it's solved, see [`Day::solve_parts`].
+    pub solve_each: SolveEach,
+    /// Benchmark parsing and solving a part that's known to work, see [`Day::bench_checked`].
+    pub bench: fn(usize, Option<&str>, &BenchConfig) -> BenchTimes,
+}
+
+fn solve_each<D: Day>(parts: &[usize], input: Option<&str>, each: &mut dyn FnMut(Solution)) {
+    D::solve_parts(parts, input, each);
+}
+
+impl DayEntry {
+    pub const fn new<D: Day + 'static>(examples: &'static [ExampleCase]) -> Self {
+        Self {
+            day: D::DAY,
+            title: D::TITLE,
+            parallel: D::PARALLEL,
+            timeout: D::TIMEOUT,
+            example_inputs: [D::EXAMPLE_INPUT_1, D::EXAMPLE_INPUT_2],
+            expected: [D::EXPECTED_1, D::EXPECTED_2],
+            examples,
+            solve: D::run_parts_with,
+            solve_each: solve_each::<D>,
+            bench: D::bench_checked,
+        }
+    }
+
+    /// Whether this day's title contains `query`, ignoring case.
+    pub fn title_matches(&self, query: &str) -> bool {
+        self.title.to_lowercase().contains(&query.to_lowercase())
+    }
+}
+
+/// A day as shown by `advent days`.
+#[derive(Clone, Debug, Serialize)]
+pub struct DayListing {
+    pub year: usize,
+    pub day: usize,
+    pub title: String,
+    pub parallel: bool,
+    /// How many extra examples the day has, see [`ExampleCase`].
+    pub examples: usize,
+}
+
+impl DayListing {
+    pub fn new(year: usize, entry: &DayEntry) -> Self {
+        Self {
+            year,
+            day: entry.day,
+            title: entry.title.to_string(),
+            parallel: entry.parallel,
+            examples: entry.examples.len(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Answer, InputError};
+
+    struct TestDay;
+
+    impl Day for TestDay {
+        type Input<'a> = String;
+
+        const DAY: usize = 3;
+        const TITLE: &'static str = "Mull It Over";
+
+        fn try_parse_input(input: &str) -> Result<String, InputError> {
+            Ok(input.to_string())
+        }
+
+        fn part_1(input: &String) -> Option<Answer> {
+            Some(input.len().into())
+        }
+    }
+
+    #[test]
+    fn test_day_entry() {
+        const ENTRY: DayEntry = DayEntry::new::<TestDay>(&[]);
+
+        assert_eq!(ENTRY.day, 3);
+        assert!(ENTRY.title_matches("mull"));
+        assert!(!ENTRY.title_matches("hysteria"));
+
+        let solutions = (ENTRY.solve)(&[1], Some("abc"), &RunConfig::default());
+        assert_eq!(solutions[0].answer, Some(Answer::Int(3)));
+    }
+}
@@ -0,0 +1,439 @@
+use std::str::FromStr;
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use crate::answer::Answer;
+use crate::bench::{BenchResult, Stats, print_bench_report};
+use crate::day::{Failure, Solution};
+use crate::history::{Change, Comparison, print_comparison};
+use crate::registry::DayListing;
+use crate::status::{PartStatus, print_status_grid};
+use crate::verify::{print_inputs_table, print_verify_table};
+
+/// How results are written to stdout.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum OutputFormat {
+    #[default]
+    Human,
+    Json,
+    Csv,
+}
+
+impl FromStr for OutputFormat {
+    type Err = String;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input {
+            "human" => Ok(Self::Human),
+            "json" => Ok(Self::Json),
+            "csv" => Ok(Self::Csv),
+            _ => Err(format!(
+                "Unknown format `{input}`, expected human, json, or csv"
+            )),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum InputKind {
+    Example,
+    Real,
+}
+
+impl InputKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Example => "example",
+            Self::Real => "real",
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Status {
+    Solved,
+    NotImplemented,
+    Pass,
+    Fail,
+    Missing,
+    /// Parsing or solving panicked or returned an [`crate::InputError`], see [`Failure`].
+    Failed,
+    /// The part ran past its timeout.
+    Timeout,
+}
+
+impl Status {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Solved => "SOLVED",
+            Self::NotImplemented => "NOT IMPLEMENTED",
+            Self::Pass => "PASS",
+            Self::Fail => "FAIL",
+            Self::Missing => "MISSING",
+            Self::Failed => "FAILED",
+            Self::Timeout => "TIMEOUT",
+        }
+    }
+}
+
+/// The outcome of running one part of one day.
+#[derive(Clone, Debug, Serialize)]
+pub struct PartResult {
+    pub year: usize,
+    pub day: usize,
+    pub part: usize,
+    pub input: InputKind,
+    /// The file the input was read from, when several inputs were run at once.
+    pub input_path: Option<String>,
+    pub status: Status,
+    pub answer: Option<Answer>,
+    pub expected: Option<String>,
+    /// `None` if the part reused input parsed for a previous part.
+    #[serde(rename = "parse_time_ns", with = "crate::bench::nanos::option")]
+    pub parse_time: Option<Duration>,
+    #[serde(rename = "solve_time_ns", with = "crate::bench::nanos")]
+    pub solve_time: Duration,
+    pub note: Option<String>,
+}
+
+impl PartResult {
+    pub fn new(year: usize, day: usize, part: usize, input: InputKind, solution: Solution) -> Self {
+        let (status, note) = match solution.failure {
+            Some(Failure::Panic(e) | Failure::Error(e)) => (Status::Failed, Some(e)),
+            Some(Failure::Timeout) => (
+                Status::Timeout,
+                Some(format!("timed out after {:.2?}", solution.solve_time)),
+            ),
+            None if solution.answer.is_some() => (Status::Solved, None),
+            None => (Status::NotImplemented, None),
+        };
+
+        Self {
+            year,
+            day,
+            part,
+            input,
+            input_path: None,
+            status,
+            answer: solution.answer,
+            expected: None,
+            parse_time: solution.parse_time,
+            solve_time: solution.solve_time,
+            note,
+        }
+    }
+
+    /// Time spent parsing, if this part did, and solving.
+    pub fn total_time(&self) -> Duration {
+        self.parse_time.unwrap_or_default() + self.solve_time
+    }
+
+    /// A part that couldn't be run because it has no input.
+    pub fn missing_input(year: usize, day: usize, part: usize) -> Self {
+        Self {
+            year,
+            day,
+            part,
+            input: InputKind::Real,
+            input_path: None,
+            status: Status::Missing,
+            answer: None,
+            expected: None,
+            parse_time: None,
+            solve_time: Duration::ZERO,
+            note: Some("no input".to_string()),
+        }
+    }
+}
+
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+fn print_csv(header: &[&str], rows: impl Iterator<Item = Vec<String>>) {
+    println!("{}", header.join(","));
+    for row in rows {
+        let row = row.iter().map(|f| csv_field(f)).collect::<Vec<_>>();
+        println!("{}", row.join(","));
+    }
+}
+
+fn print_json<T: Serialize>(value: &T) {
+    println!("{}", serde_json::to_string_pretty(value).unwrap());
+}
+
+fn print_results_csv(results: &[PartResult]) {
+    let header = [
+        "year",
+        "day",
+        "part",
+        "input",
+        "input_path",
+        "status",
+        "answer",
+        "expected",
+        "parse_time_ns",
+        "solve_time_ns",
+        "note",
+    ];
+    let rows = results.iter().map(|r| {
+        vec![
+            r.year.to_string(),
+            r.day.to_string(),
+            r.part.to_string(),
+            r.input.as_str().to_string(),
+            r.input_path.clone().unwrap_or_default(),
+            r.status.as_str().to_lowercase().replace(' ', "_"),
+            r.answer.as_ref().map(Answer::to_string).unwrap_or_default(),
+            r.expected.clone().unwrap_or_default(),
+            r.parse_time
+                .map(|t| t.as_nanos().to_string())
+                .unwrap_or_default(),
+            r.solve_time.as_nanos().to_string(),
+            r.note.clone().unwrap_or_default(),
+        ]
+    });
+    print_csv(&header, rows);
+}
+
+fn print_results_human(results: &[PartResult]) {
+    let mut current_year = None;
+    let mut current_day = None;
+    for r in results {
+        if current_year != Some(r.year) {
+            println!("Year {}:", r.year);
+            current_year = Some(r.year);
+            current_day = None;
+        }
+        if current_day != Some(r.day) {
+            let label = match r.input {
+                InputKind::Example => " [example]",
+                InputKind::Real => "",
+            };
+            println!("  Day {}{}:", r.day, label);
+            current_day = Some(r.day);
+        }
+        if r.status == Status::Timeout {
+            println!("    Part {}: TIMEOUT after {:.2?}", r.part, r.solve_time);
+            continue;
+        }
+        let parse = r
+            .parse_time
+            .map(|t| format!("{t:.2?}"))
+            .unwrap_or_else(|| "reused".to_string());
+        let answer = match (r.status, &r.answer, &r.note) {
+            (Status::Failed, _, note) => {
+                // Errors pointing into the input span several lines, keep them under the part
+                let note = note.as_deref().unwrap_or("").replace('\n', "\n      ");
+                format!("FAILED: {note}")
+            }
+            (_, Some(answer @ Answer::Art(_)), _) => {
+                // Art goes on lines of its own, indented under the part
+                let art = answer.to_string().replace('\n', "\n      ");
+                format!("\n      {art}\n     ")
+            }
+            (_, Some(answer), _) => answer.to_string(),
+            (_, None, _) => "Not implemented".to_string(),
+        };
+        println!(
+            "    Part {}: {} (parse {}, part {:.2?}, total {:.2?})",
+            r.part,
+            answer,
+            parse,
+            r.solve_time,
+            r.total_time()
+        );
+    }
+}
+
+/// Print the results of solving parts.
+pub fn print_results(results: &[PartResult], format: OutputFormat) {
+    match format {
+        OutputFormat::Human => print_results_human(results),
+        OutputFormat::Json => print_json(&results),
+        OutputFormat::Csv => print_results_csv(results),
+    }
+}
+
+/// Print the results of verifying parts against their recorded answers.
+pub fn print_verify(results: &[PartResult], format: OutputFormat) {
+    match format {
+        OutputFormat::Human => print_verify_table(results),
+        OutputFormat::Json => print_json(&results),
+        OutputFormat::Csv => print_results_csv(results),
+    }
+}
+
+/// Print the results of running a day against several inputs, checked against
+/// the answers recorded next to each input.
+pub fn print_inputs(results: &[PartResult], format: OutputFormat) {
+    match format {
+        OutputFormat::Human => print_inputs_table(results),
+        OutputFormat::Json => print_json(&results),
+        OutputFormat::Csv => print_results_csv(results),
+    }
+}
+
+fn print_bench_csv(results: &[BenchResult], comparison: Option<&Comparison>) {
+    let stats = |prefix: &str| {
+        [
+            "mean_ns",
+            "median_ns",
+            "std_dev_ns",
+            "min_ns",
+            "max_ns",
+            "p95_ns",
+            "p99_ns",
+            "iterations",
+        ]
+        .map(|s| format!("{prefix}_{s}"))
+    };
+    let stat_fields = |s: &Stats| {
+        [s.mean, s.median, s.std_dev, s.min, s.max, s.p95, s.p99]
+            .map(|d| d.as_nanos().to_string())
+            .into_iter()
+            .chain([s.iterations.to_string()])
+    };
+
+    let mut header = ["year", "day", "part", "input"].map(String::from).to_vec();
+    header.extend(stats("parse"));
+    header.extend(stats("solve"));
+    if comparison.is_some() {
+        header.extend(
+            [
+                "baseline_parse_mean_ns",
+                "parse_delta_percent",
+                "baseline_solve_mean_ns",
+                "solve_delta_percent",
+                "change",
+            ]
+            .map(String::from),
+        );
+    }
+    let header = header.iter().map(String::as_str).collect::<Vec<_>>();
+
+    let rows = results.iter().map(|r| {
+        let mut row = [r.year, r.day, r.part]
+            .map(|n| n.to_string())
+            .into_iter()
+            .chain([r.input.as_str().to_string()])
+            .chain(stat_fields(&r.times.parse))
+            .chain(stat_fields(&r.times.solve))
+            .collect::<Vec<_>>();
+        if let Some(comparison) = comparison {
+            row.extend(match comparison.get(r) {
+                Some(p) => vec![
+                    p.parse.was.as_nanos().to_string(),
+                    format!("{:.1}", p.parse.delta_percent),
+                    p.solve.was.as_nanos().to_string(),
+                    format!("{:.1}", p.solve.delta_percent),
+                    match p.change {
+                        Change::Improved => "improved",
+                        Change::Regressed => "regressed",
+                        Change::NoChange => "no_change",
+                    }
+                    .to_string(),
+                ],
+                None => vec![String::new(); 5],
+            });
+        }
+        row
+    });
+    print_csv(&header, rows);
+}
+
+/// Print the results of benchmarking parts, along with how they changed from a baseline if given.
+pub fn print_bench(results: &[BenchResult], comparison: Option<&Comparison>, format: OutputFormat) {
+    match (format, comparison) {
+        (OutputFormat::Human, _) => {
+            print_bench_report(results);
+            if let Some(comparison) = comparison {
+                print_comparison(comparison);
+            }
+        }
+        (OutputFormat::Json, None) => print_json(&results),
+        (OutputFormat::Json, Some(comparison)) => print_json(&serde_json::json!({
+            "results": results,
+            "comparison": comparison,
+        })),
+        (OutputFormat::Csv, _) => print_bench_csv(results, comparison),
+    }
+}
+
+/// Print how far along each part is.
+pub fn print_status(statuses: &[PartStatus], format: OutputFormat) {
+    match format {
+        OutputFormat::Human => print_status_grid(statuses),
+        OutputFormat::Json => print_json(&statuses),
+        OutputFormat::Csv => print_csv(
+            &["year", "day", "part", "progress"],
+            statuses.iter().map(|s| {
+                vec![
+                    s.year.to_string(),
+                    s.day.to_string(),
+                    s.part.to_string(),
+                    s.progress.as_str().replace(' ', "_"),
+                ]
+            }),
+        ),
+    }
+}
+
+pub fn print_days(days: &[DayListing], format: OutputFormat) {
+    match format {
+        OutputFormat::Human => {
+            for d in days {
+                let mut notes = vec![];
+                if !d.parallel {
+                    notes.push("runs alone".to_string());
+                }
+                if d.examples > 0 {
+                    notes.push(format!("{} extra examples", d.examples));
+                }
+                let notes = if notes.is_empty() {
+                    String::new()
+                } else {
+                    format!(" ({})", notes.join(", "))
+                };
+                let title = if d.title.is_empty() {
+                    String::new()
+                } else {
+                    format!(": {}", d.title)
+                };
+                println!("{} Day {:>2}{title}{notes}", d.year, d.day);
+            }
+        }
+        OutputFormat::Json => print_json(&days),
+        OutputFormat::Csv => print_csv(
+            &["year", "day", "title", "parallel", "examples"],
+            days.iter().map(|d| {
+                vec![
+                    d.year.to_string(),
+                    d.day.to_string(),
+                    d.title.clone(),
+                    d.parallel.to_string(),
+                    d.examples.to_string(),
+                ]
+            }),
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_csv_field() {
+        assert_eq!(csv_field("123"), "123");
+        assert_eq!(csv_field("1,2,3"), "\"1,2,3\"");
+        assert_eq!(csv_field("say \"hi\""), "\"say \"\"hi\"\"\"");
+    }
+}
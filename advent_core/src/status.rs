@@ -0,0 +1,184 @@
+use std::collections::BTreeMap;
+
+use serde::Serialize;
+
+use crate::report::{InputKind, PartResult, Status};
+
+/// How far along a part is.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Progress {
+    /// The part gives no answer.
+    Missing,
+    /// An example or the real input gives the wrong answer, panics, or times out.
+    Failing,
+    /// The part gives answers, but none of them are checked.
+    Implemented,
+    /// Every example with an expected answer passes.
+    Example,
+    /// The answer for the stored input matches the recorded one.
+    Verified,
+}
+
+impl Progress {
+    pub fn symbol(&self) -> char {
+        match self {
+            Self::Missing => '.',
+            Self::Failing => '!',
+            Self::Implemented => 'o',
+            Self::Example => '+',
+            Self::Verified => '*',
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Missing => "missing",
+            Self::Failing => "failing",
+            Self::Implemented => "implemented",
+            Self::Example => "example passes",
+            Self::Verified => "verified",
+        }
+    }
+}
+
+/// How far along one part of one day is.
+#[derive(Clone, Debug, Serialize)]
+pub struct PartStatus {
+    pub year: usize,
+    pub day: usize,
+    pub part: usize,
+    pub progress: Progress,
+}
+
+fn progress(results: &[&PartResult]) -> Progress {
+    let ran = results
+        .iter()
+        .any(|r| r.answer.is_some() || matches!(r.status, Status::Failed | Status::Timeout));
+    let failed = results
+        .iter()
+        .any(|r| matches!(r.status, Status::Fail | Status::Failed | Status::Timeout));
+    let passed = |kind| {
+        results
+            .iter()
+            .any(|r| r.input == kind && r.status == Status::Pass)
+    };
+
+    if !ran {
+        Progress::Missing
+    } else if failed {
+        Progress::Failing
+    } else if passed(InputKind::Real) {
+        Progress::Verified
+    } else if passed(InputKind::Example) {
+        Progress::Example
+    } else {
+        Progress::Implemented
+    }
+}
+
+/// Work out how far along each part is from checking its examples and verifying
+/// its real input, see [`crate::Year::check_examples`] and [`crate::Year::verify_dp`].
+pub fn part_statuses(examples: &[PartResult], verified: &[PartResult]) -> Vec<PartStatus> {
+    let mut parts = BTreeMap::<(usize, usize, usize), Vec<&PartResult>>::new();
+    for result in examples.iter().chain(verified) {
+        parts
+            .entry((result.year, result.day, result.part))
+            .or_default()
+            .push(result);
+    }
+
+    parts
+        .into_iter()
+        .map(|((year, day, part), results)| PartStatus {
+            year,
+            day,
+            part,
+            progress: progress(&results),
+        })
+        .collect()
+}
+
+/// Print a grid for each year with a column per day and a row per part.
+pub fn print_status_grid(statuses: &[PartStatus]) {
+    let mut years = BTreeMap::<usize, BTreeMap<usize, [Option<Progress>; 2]>>::new();
+    for s in statuses {
+        years.entry(s.year).or_default().entry(s.day).or_default()[s.part - 1] = Some(s.progress);
+    }
+
+    for (i, (year, days)) in years.iter().enumerate() {
+        if i > 0 {
+            println!();
+        }
+        let header = days
+            .keys()
+            .map(|day| format!("{day:>3}"))
+            .collect::<String>();
+        println!("{year:<6}{header}");
+        for part in 1..=2 {
+            let row = days
+                .values()
+                .map(|parts| format!("{:>3}", parts[part - 1].map_or(' ', |p| p.symbol())))
+                .collect::<String>();
+            println!("{:<6}{row}", format!("Part {part}"));
+        }
+    }
+
+    let legend = [
+        Progress::Verified,
+        Progress::Example,
+        Progress::Implemented,
+        Progress::Failing,
+        Progress::Missing,
+    ]
+    .map(|p| format!("{} {}", p.symbol(), p.as_str()))
+    .join(", ");
+    println!("\n{legend}");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::answer::Answer;
+
+    fn result(day: usize, input: InputKind, status: Status, answer: Option<&str>) -> PartResult {
+        let mut result = PartResult::missing_input(2024, day, 1);
+        result.input = input;
+        result.status = status;
+        result.answer = answer.map(Answer::from);
+        result
+    }
+
+    #[test]
+    fn test_part_statuses() {
+        let examples = [
+            result(1, InputKind::Example, Status::Pass, Some("1")),
+            result(2, InputKind::Example, Status::Pass, Some("1")),
+            result(3, InputKind::Example, Status::Fail, Some("1")),
+            result(4, InputKind::Example, Status::Missing, Some("1")),
+            result(5, InputKind::Example, Status::Fail, None),
+        ];
+        let verified = [
+            result(1, InputKind::Real, Status::Pass, Some("2")),
+            result(2, InputKind::Real, Status::Missing, None),
+            result(3, InputKind::Real, Status::Pass, Some("2")),
+            result(4, InputKind::Real, Status::Missing, Some("2")),
+            result(5, InputKind::Real, Status::Missing, None),
+        ];
+
+        let progress = part_statuses(&examples, &verified)
+            .into_iter()
+            .map(|s| s.progress)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            progress,
+            vec![
+                Progress::Verified,
+                Progress::Example,
+                Progress::Failing,
+                Progress::Implemented,
+                Progress::Missing,
+            ]
+        );
+    }
+}
@@ -0,0 +1,148 @@
+use crate::answer::Answer;
+use crate::report::{PartResult, Status};
+
+/// Compare a solved part against the answer recorded for it.
+///
+/// Parts that panicked or timed out stay failed regardless of what was expected.
+pub fn check_answer(mut result: PartResult, expected: Option<String>) -> PartResult {
+    if matches!(result.status, Status::Failed | Status::Timeout) {
+        result.expected = expected;
+        return result;
+    }
+
+    let (status, note) = match (&result.answer, &expected) {
+        (None, None) => (Status::Missing, "not implemented"),
+        (None, Some(_)) => (Status::Fail, "not implemented"),
+        (Some(_), None) => (Status::Missing, "no recorded answer"),
+        (Some(a), Some(e)) if a.matches(e) => (Status::Pass, ""),
+        (Some(_), Some(_)) => (Status::Fail, "wrong answer"),
+    };
+
+    result.status = status;
+    result.note = (!note.is_empty()).then(|| note.to_string());
+    result.expected = expected;
+    result
+}
+
+/// An answer written on a single line so it fits in a table cell.
+fn answer_cell(answer: Option<String>) -> String {
+    answer.map_or_else(|| "-".to_string(), |a| a.replace('\n', "\\n"))
+}
+
+/// The first line of a note, errors that point into the input go on to show where.
+fn note_cell(note: Option<&str>) -> String {
+    note.and_then(|n| n.lines().next())
+        .unwrap_or_default()
+        .to_string()
+}
+
+fn print_table<const N: usize>(header: [&str; N], rows: &[[String; N]]) {
+    let widths = header.map(str::len);
+    let widths = rows.iter().fold(widths, |mut widths, row| {
+        for (w, cell) in widths.iter_mut().zip(row) {
+            *w = (*w).max(cell.len());
+        }
+        widths
+    });
+
+    let print_row = |row: &[&str]| {
+        let line = row
+            .iter()
+            .zip(widths)
+            .map(|(cell, w)| format!("{cell:<w$}"))
+            .collect::<Vec<_>>()
+            .join("  ");
+        println!("{}", line.trim_end());
+    };
+
+    print_row(&header);
+    for row in rows.iter() {
+        print_row(&row.each_ref().map(String::as_str));
+    }
+}
+
+fn is_failure(status: Status) -> bool {
+    matches!(status, Status::Fail | Status::Failed | Status::Timeout)
+}
+
+fn print_summary(results: &[PartResult]) {
+    let count = |status| results.iter().filter(|v| v.status == status).count();
+    println!(
+        "\n{} passed, {} failed, {} missing",
+        count(Status::Pass),
+        results.iter().filter(|v| is_failure(v.status)).count(),
+        count(Status::Missing)
+    );
+}
+
+/// Print a table of verification results followed by a summary line.
+pub fn print_verify_table(results: &[PartResult]) {
+    let header = [
+        "Year", "Day", "Part", "Status", "Answer", "Expected", "Note",
+    ];
+    let rows = results
+        .iter()
+        .map(|v| {
+            [
+                v.year.to_string(),
+                v.day.to_string(),
+                v.part.to_string(),
+                v.status.as_str().to_string(),
+                answer_cell(v.answer.as_ref().map(Answer::to_string)),
+                answer_cell(v.expected.clone()),
+                note_cell(v.note.as_deref()),
+            ]
+        })
+        .collect::<Vec<_>>();
+
+    print_table(header, &rows);
+    print_summary(results);
+}
+
+/// Print a table of results for one day run against several inputs, then which inputs
+/// disagree with the answers recorded for them.
+pub fn print_inputs_table(results: &[PartResult]) {
+    let header = ["Input", "Part", "Status", "Answer", "Expected", "Note"];
+    let rows = results
+        .iter()
+        .map(|v| {
+            [
+                v.input_path.clone().unwrap_or_default(),
+                v.part.to_string(),
+                v.status.as_str().to_string(),
+                answer_cell(v.answer.as_ref().map(Answer::to_string)),
+                answer_cell(v.expected.clone()),
+                note_cell(v.note.as_deref()),
+            ]
+        })
+        .collect::<Vec<_>>();
+
+    print_table(header, &rows);
+    print_summary(results);
+
+    let failures = results
+        .iter()
+        .filter(|v| is_failure(v.status))
+        .collect::<Vec<_>>();
+
+    if !failures.is_empty() {
+        println!("\nInputs that disagree with their recorded answers:");
+        for v in failures {
+            let detail = match (v.status, &v.answer, &v.expected) {
+                (Status::Fail, Some(answer), Some(expected)) => {
+                    format!("got {answer}, expected {expected}")
+                }
+                _ => v
+                    .note
+                    .clone()
+                    .unwrap_or_else(|| v.status.as_str().to_string()),
+            };
+            println!(
+                "  {} part {}: {}",
+                v.input_path.as_deref().unwrap_or("-"),
+                v.part,
+                detail.replace('\n', "\n    ")
+            );
+        }
+    }
+}
@@ -0,0 +1,62 @@
+use std::collections::BTreeSet;
+use std::path::{self, Path, PathBuf};
+use std::sync::mpsc;
+use std::time::Duration;
+
+use notify::{Event, RecursiveMode, Watcher};
+
+/// How long to keep collecting events after the first one, editors tend to save in bursts.
+const DEBOUNCE: Duration = Duration::from_millis(200);
+
+fn is_relevant(event: &Event, paths: &[PathBuf]) -> bool {
+    !event.kind.is_access()
+        && event
+            .paths
+            .iter()
+            .any(|changed| paths.iter().any(|p| changed.starts_with(p)))
+}
+
+/// Call `on_change` once, then again every time any of `paths` is created, changed, or removed.
+///
+/// Paths don't need to exist yet, but changes are only noticed if the directory they'd be in does.
+/// Directories are watched along with everything directly inside them.
+pub fn watch(paths: &[PathBuf], mut on_change: impl FnMut()) -> notify::Result<()> {
+    let paths = paths
+        .iter()
+        .map(|p| path::absolute(p).map_err(notify::Error::io))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    // Watch directories rather than the files in them so editors that save by
+    // replacing the file, and files that don't exist yet, are still picked up
+    let dirs = paths
+        .iter()
+        .filter_map(|p| {
+            if p.is_dir() {
+                Some(p.as_path())
+            } else {
+                p.parent()
+            }
+        })
+        .filter(|dir| dir.is_dir())
+        .collect::<BTreeSet<&Path>>();
+
+    let (tx, rx) = mpsc::channel();
+    let mut watcher = notify::recommended_watcher(tx)?;
+    for dir in dirs {
+        watcher.watch(dir, RecursiveMode::NonRecursive)?;
+    }
+
+    on_change();
+
+    while let Ok(event) = rx.recv() {
+        if !is_relevant(&event?, &paths) {
+            continue;
+        }
+
+        while rx.recv_timeout(DEBOUNCE).is_ok() {}
+
+        on_change();
+    }
+
+    Ok(())
+}
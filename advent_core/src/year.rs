@@ -1,48 +1,342 @@
-use crate::parser::{Selection, DP};
+use std::io;
 
-use super::MAX_DAY;
+use crate::bench::{BenchConfig, BenchResult, BenchTimes};
+use crate::child::solve_in_child;
+use crate::day::{ExampleCase, RunConfig, Solution, benchable};
+use crate::inputs::{InputSource, InputStore, normalize_input};
+use crate::parallel::{map_ordered, run_limited};
+use crate::parser::DP;
+use crate::registry::DayEntry;
+use crate::report::{InputKind, PartResult};
+use crate::verify::check_answer;
 
 pub trait Year {
     const YEAR: usize;
 
-    fn solve_day(day: usize, part: usize, input: Option<&str>) -> Option<String>;
+    /// Every day of this year in order, see [`DayEntry`].
+    const ENTRIES: &'static [DayEntry];
 
-    fn bench_day(day: usize, part: usize, input: Option<&str>);
+    /// How many days this year has, see [`crate::default_days`].
+    const DAYS: usize = Self::ENTRIES.len();
 
-    fn solve_day_both_parts(day: usize, extra_indent: &str);
+    /// Look up a day, `None` if this year doesn't have it.
+    fn entry(day: usize) -> Option<&'static DayEntry> {
+        Self::ENTRIES.iter().find(|entry| entry.day == day)
+    }
 
-    fn solve_all_days() {
-        println!("Year {}:", Self::YEAR);
-        for day in 1..=MAX_DAY {
-            Self::solve_day_both_parts(day, "  ");
-        }
+    /// Run parts of a day, `None` if this year doesn't have that day.
+    ///
+    /// Parts with a timeout run in a child process when they can, see [`crate::enable_child_processes`].
+    fn solve_day(
+        day: usize,
+        parts: &[usize],
+        input: Option<&str>,
+        config: &RunConfig,
+    ) -> Option<Vec<Solution>> {
+        let entry = Self::entry(day)?;
+        let solutions = entry
+            .timeout
+            .or(config.timeout)
+            .and_then(|timeout| solve_in_child(Self::YEAR, day, parts, input, timeout))
+            .unwrap_or_else(|| (entry.solve)(parts, input, config));
+        Some(solutions)
+    }
+
+    /// The example input of a part, `None` if this year doesn't have that day.
+    fn example_input(day: usize, part: usize) -> Option<&'static str> {
+        Self::entry(day).map(|entry| entry.example_inputs[part - 1])
+    }
+
+    /// The extra examples of a day, see [`ExampleCase`].
+    fn example_cases(day: usize) -> &'static [ExampleCase] {
+        Self::entry(day).map_or(&[], |entry| entry.examples)
+    }
+
+    /// The expected answer to a part's example, `None` if this year doesn't have that day.
+    fn expected(day: usize, part: usize) -> Option<&'static str> {
+        Self::entry(day).map(|entry| entry.expected[part - 1])
     }
 
-    fn run_dp(input: Option<&str>, dp: DP) {
-        match dp.day {
-            Selection::All => {
-                Self::solve_all_days();
+    /// Whether a day can run alongside others, see [`crate::Day::PARALLEL`].
+    fn is_parallel(day: usize) -> bool {
+        Self::entry(day).is_none_or(|entry| entry.parallel)
+    }
+
+    /// Benchmark a part, `None` if this year doesn't have that day or the part doesn't work.
+    ///
+    /// The part is run once with [`Year::solve_day`] first, so a part that hangs is killed
+    /// rather than left running while later parts are benchmarked.
+    fn bench_day(
+        day: usize,
+        part: usize,
+        input: Option<&str>,
+        config: &BenchConfig,
+    ) -> Option<BenchTimes> {
+        let entry = Self::entry(day)?;
+        let run = RunConfig {
+            timeout: config.timeout,
+            ..RunConfig::default()
+        };
+        let solution = Self::solve_day(day, &[part], input, &run)?.remove(0);
+        benchable(day, part, &solution).then(|| (entry.bench)(part, input, config))
+    }
+
+    /// Get the input to run a day against, from `source` if given, otherwise the input
+    /// saved in the store. `None` falls back to each part's example.
+    fn day_input(
+        day: usize,
+        source: Option<&InputSource>,
+        store: Option<&InputStore>,
+    ) -> Option<String> {
+        match source {
+            None => store.and_then(|store| store.get(Self::YEAR, day)),
+            Some(InputSource::Literal(text)) => Some(text.clone()),
+            Some(InputSource::Stored) => {
+                let input = store.cloned().unwrap_or_default().get(Self::YEAR, day);
+                if input.is_none() {
+                    eprintln!("No stored input for {} day {day}", Self::YEAR);
+                }
+                input
+            }
+            Some(InputSource::Example(None)) => None,
+            Some(InputSource::Example(Some(part))) => {
+                Self::example_input(day, *part).map(|e| normalize_input(e).into_owned())
             }
-            Selection::Single(day) => match dp.part {
-                Selection::All => {
-                    Self::solve_day_both_parts(day, "");
+            Some(source @ (InputSource::File(_) | InputSource::Stdin)) => {
+                match source.clone().load() {
+                    Ok(InputSource::Literal(text)) => Some(text),
+                    Ok(_) => None,
+                    Err(e) => {
+                        eprintln!("Failed to read input: {e}");
+                        None
+                    }
                 }
-                Selection::Single(part) => {
-                    Self::solve_day(day, part, input);
+            }
+        }
+    }
+
+    /// Run parts of a day against an input, or their examples if `input` is `None`.
+    fn run_day(
+        day: usize,
+        parts: &[usize],
+        input: Option<&str>,
+        config: &RunConfig,
+    ) -> Vec<PartResult> {
+        let kind = if input.is_some() {
+            InputKind::Real
+        } else {
+            InputKind::Example
+        };
+
+        let Some(solutions) = Self::solve_day(day, parts, input, config) else {
+            eprintln!("{} has no day {day}", Self::YEAR);
+            return vec![];
+        };
+
+        solutions
+            .into_iter()
+            .zip(parts)
+            .map(|(solution, &part)| PartResult::new(Self::YEAR, day, part, kind, solution))
+            .collect()
+    }
+
+    fn solve_days(
+        days: &[usize],
+        parts: &[usize],
+        source: Option<&InputSource>,
+        store: Option<&InputStore>,
+        config: &RunConfig,
+    ) -> Vec<PartResult> {
+        let mut results = map_ordered(days.to_vec(), config.jobs, |day| {
+            run_limited(config.jobs, Self::is_parallel(day), || {
+                let input = Self::day_input(day, source, store);
+                Self::run_day(day, parts, input.as_deref(), config)
+            })
+        })
+        .concat();
+
+        if let Some(InputSource::Example(_)) = source {
+            for result in results.iter_mut() {
+                result.input = InputKind::Example;
+            }
+        }
+
+        results
+    }
+
+    fn solve_all_days(store: Option<&InputStore>, config: &RunConfig) -> Vec<PartResult> {
+        let days = Self::ENTRIES
+            .iter()
+            .map(|entry| entry.day)
+            .collect::<Vec<_>>();
+        Self::solve_days(&days, &[1, 2], None, store, config)
+    }
+
+    fn selected_days(dp: &DP) -> Vec<usize> {
+        let days = dp
+            .day
+            .select(Self::ENTRIES.iter().map(|entry| entry.day))
+            .collect::<Vec<_>>();
+        if days.is_empty() {
+            eprintln!("No days of {} match the selection", Self::YEAR);
+        }
+        days
+    }
+
+    fn run_dp(
+        source: Option<&InputSource>,
+        dp: DP,
+        store: Option<&InputStore>,
+        config: &RunConfig,
+    ) -> Vec<PartResult> {
+        let days = Self::selected_days(&dp);
+        let parts = dp.part.select(1..=2).collect::<Vec<_>>();
+
+        let source = if days.len() > 1 && source.is_some_and(InputSource::is_fixed) {
+            eprintln!("Ignoring input, more than one day is selected");
+            None
+        } else {
+            source
+        };
+
+        Self::solve_days(&days, &parts, source, store, config)
+    }
+
+    fn bench_dp(
+        source: Option<&InputSource>,
+        dp: DP,
+        store: Option<&InputStore>,
+        config: &BenchConfig,
+    ) -> Vec<BenchResult> {
+        let days = Self::selected_days(&dp);
+        let source = if days.len() > 1 && source.is_some_and(InputSource::is_fixed) {
+            eprintln!("Ignoring input, more than one day is selected");
+            None
+        } else {
+            source
+        };
+        let mut results = vec![];
+        for day in days {
+            let input = Self::day_input(day, source, store);
+            let kind = match (&input, source) {
+                (Some(_), Some(InputSource::Example(_))) | (None, _) => InputKind::Example,
+                (Some(_), _) => InputKind::Real,
+            };
+            for part in dp.part.select(1..=2) {
+                if let Some(times) = Self::bench_day(day, part, input.as_deref(), config) {
+                    results.push(BenchResult {
+                        year: Self::YEAR,
+                        day,
+                        part,
+                        input: kind,
+                        times,
+                    });
                 }
-            },
+            }
         }
+        results
+    }
+
+    /// Check the selected parts' examples against their expected answers.
+    fn check_examples(dp: DP, config: &RunConfig) -> Vec<PartResult> {
+        let parts = dp.part.select(1..=2).collect::<Vec<_>>();
+        let days = Self::selected_days(&dp);
+
+        map_ordered(days, config.jobs, |day| {
+            run_limited(config.jobs, Self::is_parallel(day), || {
+                let results = Self::run_day(day, &parts, None, config)
+                    .into_iter()
+                    .map(|result| {
+                        let expected = Self::expected(result.day, result.part)
+                            .filter(|e| !e.is_empty())
+                            .map(str::to_string);
+                        check_answer(result, expected)
+                    });
+
+                let cases = Self::example_cases(day)
+                    .iter()
+                    .filter(|case| parts.contains(&case.part))
+                    .filter_map(|case| Self::check_case(day, case, config));
+
+                results.chain(cases).collect::<Vec<_>>()
+            })
+        })
+        .into_iter()
+        .flatten()
+        .collect()
     }
 
-    fn bench_dp(input: Option<&str>, dp: DP) {
-        match dp.day {
-            Selection::Single(day) => match dp.part {
-                Selection::Single(part) => {
-                    Self::bench_day(day, part, input);
+    /// Check one of a day's extra examples, noting which one it was in the result.
+    fn check_case(day: usize, case: &ExampleCase, config: &RunConfig) -> Option<PartResult> {
+        let input = normalize_input(case.input);
+        let solution = Self::solve_day(day, &[case.part], Some(&input), config)?
+            .into_iter()
+            .next()?;
+
+        let mut result = PartResult::new(Self::YEAR, day, case.part, InputKind::Example, solution);
+        result.input_path = Some(format!("examples/day_{day}/{}.txt", case.name));
+
+        let mut result = check_answer(result, Some(case.expected.trim().to_string()));
+        result.note = Some(match result.note {
+            Some(note) => format!("{}: {note}", case.name),
+            None => case.name.to_string(),
+        });
+        Some(result)
+    }
+
+    /// Check the selected parts against the answers recorded in the store.
+    ///
+    /// With `record`, answers for parts that don't have one recorded yet are saved.
+    fn verify_dp(
+        dp: DP,
+        store: &InputStore,
+        record: bool,
+        config: &RunConfig,
+    ) -> io::Result<Vec<PartResult>> {
+        let mut answers = store.answers(Self::YEAR)?;
+        let parts = dp.part.select(1..=2).collect::<Vec<_>>();
+
+        let mut results = vec![];
+        let mut changed = false;
+
+        let runs = map_ordered(Self::selected_days(&dp), config.jobs, |day| {
+            let day_results = store.get(Self::YEAR, day).map(|input| {
+                run_limited(config.jobs, Self::is_parallel(day), || {
+                    Self::run_day(day, &parts, Some(&input), config)
+                })
+            });
+            (day, day_results)
+        });
+
+        for (day, day_results) in runs {
+            let Some(day_results) = day_results else {
+                results.extend(
+                    parts
+                        .iter()
+                        .map(|&part| PartResult::missing_input(Self::YEAR, day, part)),
+                );
+                continue;
+            };
+
+            for result in day_results {
+                let expected = answers.get(day, result.part).map(str::to_string);
+
+                if record
+                    && expected.is_none()
+                    && let Some(answer) = &result.answer
+                {
+                    answers.insert(day, result.part, answer.to_string());
+                    changed = true;
                 }
-                _ => panic!("Cannot bench all parts, sorry :("),
-            },
-            _ => panic!("Cannot bench all days, sorry :("),
+
+                results.push(check_answer(result, expected));
+            }
         }
+
+        if changed {
+            answers.save()?;
+        }
+
+        Ok(results)
     }
 }
@@ -1,233 +1,514 @@
 extern crate proc_macro;
 
+use std::path::PathBuf;
+
+use advent_core::MAX_DAY;
 use proc_macro::TokenStream;
+use proc_macro2::{Literal, Span, TokenStream as TokenStream2};
+use quote::{format_ident, quote};
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::{parse_macro_input, Expr, Ident, ImplItem, Item, LitInt, LitStr, Token};
+
+/// An extra example for a part, read from `examples/day_N/<part>_<name>.txt`
+/// with its answer in the matching `.expected` file.
+struct ExampleCase {
+    part: usize,
+    /// The file name without its extension, used to find the input and answer.
+    stem: String,
+    /// The name of the case made into a valid identifier, used to name its test.
+    ident: Ident,
+    has_expected: bool,
+}
 
-fn make_day_mods(days: usize) -> String {
-    (1..=days)
-        .map(|day| format!("pub mod day_{day};", day = day))
-        .collect::<Vec<_>>()
-        .join("\n")
-}
-
-fn make_use_days(days: usize) -> String {
-    (1..=days)
-        .map(|day| format!("use day_{day}::Day{day};", day = day))
-        .collect::<Vec<_>>()
-        .join("\n")
-}
-
-fn make_day_match(inner: &str, days: usize) -> String {
-    (1..=days)
-        .map(|day| format!("{day} => {},", inner.replace("{day}", &day.to_string())))
-        .collect::<Vec<_>>()
-        .join("\n")
-}
-
-fn make_day_tests(days: usize) -> String {
-    (1..=days)
-        .map(|day| {
-            format!(
-                "
-    #[test]
-    fn test_day_{day}_part_1() {{
-        Day{day}::assert_part_1();
-    }}
-
-    #[test]
-    fn test_day_{day}_part_2() {{
-        Day{day}::assert_part_2();
-    }}"
-            )
-        })
-        .collect::<Vec<_>>()
-        .join("\n")
-}
-
-fn get_solve_day(days: usize) -> String {
-    let inner = make_day_match("Day{day}::run_part(part, input)", days);
-    let inner2 = make_day_match("{Day{day}::bench_part(part, input);}", days);
-    format!(
-        "
-    fn solve_day(day: usize, part: usize, input: Option<&str>) -> Option<String> {{
-        match day {{
-            {inner}
+impl ExampleCase {
+    fn input_path(&self, day: usize) -> LitStr {
+        LitStr::new(
+            &format!("examples/day_{day}/{}.txt", self.stem),
+            Span::call_site(),
+        )
+    }
+
+    fn expected_path(&self, day: usize) -> LitStr {
+        LitStr::new(
+            &format!("examples/day_{day}/{}.expected", self.stem),
+            Span::call_site(),
+        )
+    }
+}
+
+fn manifest_dir() -> PathBuf {
+    PathBuf::from(std::env::var("CARGO_MANIFEST_DIR").unwrap_or_default())
+}
+
+fn src_dir() -> PathBuf {
+    manifest_dir().join("src")
+}
+
+fn examples_dir(day: usize) -> PathBuf {
+    src_dir().join(format!("examples/day_{day}"))
+}
+
+fn day_path(day: usize) -> PathBuf {
+    src_dir().join(format!("day_{day}.rs"))
+}
+
+fn lit(n: usize) -> Literal {
+    Literal::usize_unsuffixed(n)
+}
+
+/// Whether a day's source defines `part_N` as anything more than a stub returning `None`.
+///
+/// Days whose source can't be read or parsed are assumed to be implemented, running them will tell.
+/// So are parts that aren't written out if the day's impl calls a macro that could define them.
+fn is_implemented(day: usize, part: usize) -> bool {
+    let Some(file) = std::fs::read_to_string(day_path(day))
+        .ok()
+        .and_then(|source| syn::parse_file(&source).ok())
+    else {
+        return true;
+    };
+
+    let names = [format!("part_{part}"), format!("try_part_{part}")];
+    let items = file
+        .items
+        .iter()
+        .filter_map(|item| match item {
+            Item::Impl(imp) => Some(&imp.items),
             _ => None,
-        }}
-    }}
-    fn bench_day(day: usize, part: usize, input: Option<&str>) {{
-        match day {{
-            {inner2}
-            _ => panic!(\"Invalid Day\"),
-        }}
-    }}"
-    )
-}
-
-fn get_solve_day_both_parts(days: usize) -> String {
-    let inner = make_day_match("Day{day}::run_all_parts(extra_indent)", days);
-    format!(
-        "
-    fn solve_day_both_parts(day: usize, extra_indent: &str) {{
-        match day {{
-            {inner}
-            _ => (),
-        }}
-    }}",
-        inner = inner
-    )
-}
-
-fn make_year_struct(year: &str, days: usize) -> String {
-    format!(
-        "
-        pub struct Year{year};
-
-        impl Year for Year{year} {{
-            const YEAR: usize = {year};
-
-            {solve_day}
-
-            {solve_day_both_parts}
-        }}",
-        solve_day = get_solve_day(days),
-        solve_day_both_parts = get_solve_day_both_parts(days)
-    )
-}
-
-fn make_tests(days: usize) -> String {
-    format!(
-        "
-    #[cfg(test)]
-    mod tests {{
-        use super::*;
-        use advent_core::{{Day, Year}};
-
-        {day_tests}
-    }}",
-        day_tests = make_day_tests(days)
-    )
+        })
+        .flatten()
+        .collect::<Vec<_>>();
+
+    let part_fn = items.iter().find_map(|item| match item {
+        ImplItem::Fn(f) if names.iter().any(|n| f.sig.ident == n) => Some(f),
+        _ => None,
+    });
+
+    match part_fn {
+        Some(f) => !matches!(
+            f.block.stmts.as_slice(),
+            [syn::Stmt::Expr(Expr::Path(p), None)] if p.path.is_ident("None")
+        ),
+        None => items
+            .iter()
+            .any(|item| matches!(item, ImplItem::Macro(m) if !m.mac.path.is_ident("day_stuff"))),
+    }
 }
 
-#[proc_macro]
-pub fn year(item: TokenStream) -> TokenStream {
-    let year = item.to_string();
+/// Mark a part's tests as ignored if it isn't implemented, so they don't pass without checking anything.
+fn ignore_attr(day: usize, part: usize) -> TokenStream2 {
+    if is_implemented(day, part) {
+        quote!()
+    } else {
+        let reason = format!("day {day} part {part} isn't implemented");
+        quote!(#[ignore = #reason])
+    }
+}
 
-    let days = if year == "2024" { 25 } else { 12 };
+fn find_cases(day: usize) -> Vec<ExampleCase> {
+    let Ok(entries) = std::fs::read_dir(examples_dir(day)) else {
+        return vec![];
+    };
+
+    let mut cases = entries
+        .filter_map(|entry| {
+            let path = entry.ok()?.path();
+            if path.extension()? != "txt" {
+                return None;
+            }
+            let stem = path.file_stem()?.to_str()?.to_string();
+            let (part, name) = stem.split_once('_')?;
+            let part = part.parse::<usize>().ok().filter(|p| (1..=2).contains(p))?;
+            let ident = name
+                .chars()
+                .map(|c| {
+                    if c.is_ascii_alphanumeric() {
+                        c.to_ascii_lowercase()
+                    } else {
+                        '_'
+                    }
+                })
+                .collect::<String>();
+            Some(ExampleCase {
+                part,
+                has_expected: path.with_extension("expected").is_file(),
+                ident: format_ident!("test_day_{}_part_{}_{}", day, part, ident),
+                stem,
+            })
+        })
+        .collect::<Vec<_>>();
 
-    let mods = make_day_mods(days);
-    let uses = make_use_days(days);
+    cases.sort_by(|a, b| a.stem.cmp(&b.stem));
+    cases
+}
 
-    let year_struct = make_year_struct(&year, days);
+fn make_case_test(day: usize, case: &ExampleCase) -> TokenStream2 {
+    let input = case.input_path(day);
+    let expected = case.expected_path(day);
+
+    if !case.has_expected {
+        let message = format!(
+            "Example `{}` has no `{}.expected` next to it",
+            input.value(),
+            case.stem
+        );
+        return quote!(compile_error!(#message););
+    }
+
+    let ignore = ignore_attr(day, case.part);
+    let (ident, part, day_ty) = (&case.ident, lit(case.part), format_ident!("Day{}", day));
+
+    quote! {
+        #[test]
+        #ignore
+        fn #ident() {
+            #day_ty::assert_case(#part, include_str!(#input), include_str!(#expected));
+        }
+    }
+}
 
-    let tests = make_tests(days);
+fn make_example_cases(day: usize) -> TokenStream2 {
+    let cases = find_cases(day)
+        .into_iter()
+        .filter(|case| case.has_expected)
+        .map(|case| {
+            let (part, name) = (lit(case.part), &case.stem);
+            let (input, expected) = (case.input_path(day), case.expected_path(day));
+            quote! {
+                ExampleCase {
+                    part: #part,
+                    name: #name,
+                    input: include_str!(#input),
+                    expected: include_str!(#expected),
+                }
+            }
+        });
+
+    quote!(&[#(#cases),*])
+}
 
-    format!(
-        "
-        {mods}
+fn make_day_tests(day: usize) -> TokenStream2 {
+    let day_ty = format_ident!("Day{}", day);
+    let test_1 = format_ident!("test_day_{}_part_1", day);
+    let test_2 = format_ident!("test_day_{}_part_2", day);
+    let (ignore_1, ignore_2) = (ignore_attr(day, 1), ignore_attr(day, 2));
+    let cases = find_cases(day)
+        .iter()
+        .map(|case| make_case_test(day, case))
+        .collect::<Vec<_>>();
+
+    quote! {
+        #[test]
+        #ignore_1
+        fn #test_1() {
+            #day_ty::assert_part_1();
+        }
+
+        #[test]
+        #ignore_2
+        fn #test_2() {
+            #day_ty::assert_part_2();
+        }
+
+        #(#cases)*
+    }
+}
 
-        use advent_core::{{Year, Day}};
-        {uses}
+/// The arguments to `year!`, either `2024` or `2024, days = 25`.
+struct YearArgs {
+    year: LitInt,
+    days: Option<LitInt>,
+}
 
-        {year_struct}
+impl Parse for YearArgs {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let year = input.parse::<LitInt>()?;
+        year.base10_parse::<usize>()?;
+
+        let mut days = None;
+        if input.parse::<Option<Token![,]>>()?.is_some() && !input.is_empty() {
+            let key = input.parse::<Ident>()?;
+            if key != "days" {
+                return Err(syn::Error::new(key.span(), "Expected `days = N`"));
+            }
+            input.parse::<Token![=]>()?;
+            days = Some(input.parse::<LitInt>()?);
+            input.parse::<Option<Token![,]>>()?;
+        }
+
+        Ok(Self { year, days })
+    }
+}
 
-        {tests}
-    "
-    )
-    .parse::<TokenStream>()
-    .unwrap()
+impl YearArgs {
+    /// How many days the year has, the highest `day_N.rs` present unless given.
+    ///
+    /// Every day up to that must have a source file, a gap is an error rather than silently
+    /// dropping the days after it.
+    fn days(&self) -> syn::Result<usize> {
+        let year = &self.year;
+        let days = match &self.days {
+            None => (1..=MAX_DAY)
+                .rev()
+                .find(|day| day_path(*day).is_file())
+                .unwrap_or(0),
+            Some(days) => days.base10_parse::<usize>()?,
+        };
+
+        let span = self.days.as_ref().map_or(year.span(), LitInt::span);
+        if days == 0 {
+            return Err(syn::Error::new(
+                span,
+                format!("Year {year} has no days, add `day_1.rs` to its `src`"),
+            ));
+        } else if days > MAX_DAY {
+            return Err(syn::Error::new(
+                span,
+                format!("Year {year} can't have more than {MAX_DAY} days"),
+            ));
+        }
+
+        let missing = (1..=days)
+            .filter(|day| !day_path(*day).is_file())
+            .map(|day| {
+                syn::Error::new(
+                    span,
+                    format!("Year {year} has {days} days but `day_{day}.rs` doesn't exist"),
+                )
+            })
+            .reduce(|mut errors, error| {
+                errors.combine(error);
+                errors
+            });
+        match missing {
+            Some(errors) => Err(errors),
+            None => Ok(days),
+        }
+    }
+}
+
+#[proc_macro]
+pub fn year(item: TokenStream) -> TokenStream {
+    let args = parse_macro_input!(item as YearArgs);
+    let days = match args.days() {
+        Ok(days) => days,
+        Err(e) => return e.to_compile_error().into(),
+    };
+
+    let year_ty = format_ident!("Year{}", args.year.base10_digits());
+    let year = &args.year;
+    let mods = (1..=days)
+        .map(|day| format_ident!("day_{}", day))
+        .collect::<Vec<_>>();
+    let day_tys = (1..=days)
+        .map(|day| format_ident!("Day{}", day))
+        .collect::<Vec<_>>();
+    let cases = (1..=days).map(make_example_cases);
+    let tests = (1..=days).map(make_day_tests);
+
+    quote! {
+        #(pub mod #mods;)*
+
+        use advent_core::{DayEntry, ExampleCase, Year};
+        #(use #mods::#day_tys;)*
+
+        pub struct #year_ty;
+
+        impl Year for #year_ty {
+            const YEAR: usize = #year;
+
+            const ENTRIES: &'static [DayEntry] = &[#(DayEntry::new::<#day_tys>(#cases)),*];
+        }
+
+        #[cfg(test)]
+        mod tests {
+            use super::*;
+            use advent_core::{Day, Year};
+
+            #(#tests)*
+        }
+    }
+    .into()
+}
+
+/// A year given to `year_runner!` or `global_runner!`, checked to be a number.
+struct YearLit {
+    lit: LitInt,
+    year: usize,
+}
+
+impl Parse for YearLit {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let lit = input.parse::<LitInt>()?;
+        let year = lit.base10_parse::<usize>()?;
+        Ok(Self { lit, year })
+    }
+}
+
+impl YearLit {
+    fn ty(&self) -> Ident {
+        format_ident!("Year{}", self.year)
+    }
+
+    fn krate(&self) -> Ident {
+        format_ident!("y_{}", self.year)
+    }
 }
 
 #[proc_macro]
 pub fn year_runner(item: TokenStream) -> TokenStream {
-    let year = item.to_string();
+    let year = parse_macro_input!(item as YearLit);
+    let (krate, ty) = (year.krate(), year.ty());
 
-    format!(
-        "
-    use advent_core::{{Year, get_dp_and_input}};
+    quote! {
+        use advent_core::{InputStore, OutputFormat, RunConfig, Year, get_dp_and_input, print_results};
 
-    use y_{year}::Year{year};
+        use #krate::#ty;
 
-    fn main() {{
-        let (dp, input) = get_dp_and_input();
-        Year{year}::run_dp(input.as_deref(), dp);
-    }}"
-    )
-    .parse::<TokenStream>()
-    .unwrap()
+        fn main() {
+            let (dp, input) = get_dp_and_input();
+            let results = #ty::run_dp(input.as_ref(), dp, Some(&InputStore::default()), &RunConfig::default());
+            print_results(&results, OutputFormat::Human);
+        }
+    }
+    .into()
 }
 
-fn make_year_match(years: &[&str], inner: &str) -> String {
-    years
-        .iter()
-        .map(|year| format!("{year} => {},", inner.replace("{year}", year.as_ref())))
-        .collect::<Vec<_>>()
-        .join("\n")
+/// Whether the crate calling the macro depends on the crate for `year`.
+fn has_year_dependency(year: &YearLit) -> bool {
+    let Ok(manifest) = std::fs::read_to_string(manifest_dir().join("Cargo.toml")) else {
+        return true;
+    };
+    let krate = year.krate().to_string();
+    manifest.lines().any(|line| {
+        line.trim_start()
+            .strip_prefix(&krate)
+            .is_some_and(|rest| rest.trim_start().starts_with(['=', '.']))
+    })
 }
 
-fn make_year_uses(years: &[&str]) -> String {
-    years
-        .iter()
-        .map(|year| format!("use y_{year}::Year{year};", year = year))
-        .collect::<Vec<_>>()
-        .join("\n")
+fn check_years(years: &[YearLit]) -> syn::Result<()> {
+    for (i, year) in years.iter().enumerate() {
+        if years[..i].iter().any(|y| y.year == year.year) {
+            return Err(syn::Error::new(
+                year.lit.span(),
+                format!("Year {} is listed twice", year.year),
+            ));
+        }
+        if !has_year_dependency(year) {
+            return Err(syn::Error::new(
+                year.lit.span(),
+                format!(
+                    "Year {} needs `{} = {{ path = \"years/{}\" }}` in `[dependencies]`",
+                    year.year,
+                    year.krate(),
+                    year.year
+                ),
+            ));
+        }
+    }
+    Ok(())
 }
 
-fn make_run_all_years(years: &[&str]) -> String {
-    years
-        .iter()
-        .map(|year| {
-            format!(
-                "Year{year}::run_dp(input.as_deref(), dp.clone());",
-                year = year
-            )
+/// A `match` on the year with an arm from `body` for each year's type,
+/// exiting with an error for years that aren't listed.
+fn make_year_match(years: &[YearLit], body: impl Fn(&Ident) -> TokenStream2) -> TokenStream2 {
+    let arms = years.iter().map(|year| {
+        let (lit, body) = (&year.lit, body(&year.ty()));
+        quote!(#lit => #body,)
+    });
+
+    quote! {
+        match year {
+            #(#arms)*
+            _ => {
+                eprintln!("Unknown year: {year}");
+                std::process::exit(1);
+            }
+        }
+    }
+}
+
+/// Every year with a crate under `years/`, in order.
+fn discover_years() -> Vec<YearLit> {
+    let Ok(entries) = std::fs::read_dir(manifest_dir().join("years")) else {
+        return vec![];
+    };
+
+    let mut years = entries
+        .filter_map(|entry| {
+            let path = entry.ok()?.path();
+            if !path.join("Cargo.toml").is_file() {
+                return None;
+            }
+            let year = path.file_name()?.to_str()?.parse::<usize>().ok()?;
+            Some(YearLit {
+                lit: LitInt::new(&year.to_string(), Span::call_site()),
+                year,
+            })
         })
-        .collect::<Vec<_>>()
-        .join("\n")
-}
-
-fn make_run_year(years: &[&str]) -> String {
-    let inner = make_year_match(years, "Year{year}::run_dp(input.as_deref(), dp)");
-    let inner2 = make_year_match(years, "Year{year}::bench_dp(input.as_deref(), dp)");
-    format!(
-        "
-    fn run_year(year: usize, dp: DP, input: Option<&str>) {{
-        match year {{
-            {inner}
-            _ => {{
-                println!(\"Unknown year: {{year}}\");
-            }}
-        }}
-    }}
-    fn bench_year(year: usize, dp: DP, input: Option<&str>) {{
-        match year {{
-            {inner2}
-            _ => {{
-                println!(\"Unknown year: {{year}}\");
-            }}
-        }}
-    }}"
-    )
+        .collect::<Vec<_>>();
+
+    years.sort_by_key(|year| year.year);
+    years
 }
 
+/// Generate a runner for each year, either those listed or every year under `years/` if none are.
 #[proc_macro]
 pub fn global_runner(item: TokenStream) -> TokenStream {
-    let item = item.to_string();
-    let years = item.split(',').map(|s| s.trim()).collect::<Vec<_>>();
-
-    let year_uses = make_year_uses(&years);
-    let run_all_years = make_run_all_years(&years);
-    let run_year = make_run_year(&years);
-
-    format!(
-        "
-    {year_uses}
-
-    {run_year}
-
-    fn run_all_years(dp: &DP, input: Option<String>) {{
-        {run_all_years}
-    }}"
-    )
-    .parse::<TokenStream>()
-    .unwrap()
+    let mut years =
+        parse_macro_input!(item with Punctuated::<YearLit, Token![,]>::parse_terminated)
+            .into_iter()
+            .collect::<Vec<_>>();
+
+    if years.is_empty() {
+        years = discover_years();
+        if years.is_empty() {
+            return syn::Error::new(Span::call_site(), "No years found under `years/`")
+                .to_compile_error()
+                .into();
+        }
+    }
+
+    if let Err(e) = check_years(&years) {
+        return e.to_compile_error().into();
+    }
+
+    let krates = years.iter().map(YearLit::krate);
+    let tys = years.iter().map(YearLit::ty);
+    let lits = years.iter().map(|year| &year.lit);
+
+    let run = make_year_match(&years, |y| quote!(#y::run_dp(input, dp, store, config)));
+    let bench = make_year_match(&years, |y| quote!(#y::bench_dp(input, dp, store, config)));
+    let verify = make_year_match(&years, |y| quote!(#y::verify_dp(dp, store, record, config)));
+    let check = make_year_match(&years, |y| quote!(#y::check_examples(dp, config)));
+    let entries = make_year_match(&years, |y| quote!(#y::ENTRIES));
+
+    quote! {
+        #(use #krates::#tys;)*
+
+        const YEARS: &[usize] = &[#(#lits),*];
+
+        fn run_year(year: usize, dp: DP, input: Option<&InputSource>, store: Option<&InputStore>, config: &RunConfig) -> Vec<PartResult> {
+            #run
+        }
+
+        fn bench_year(year: usize, dp: DP, input: Option<&InputSource>, store: Option<&InputStore>, config: &BenchConfig) -> Vec<BenchResult> {
+            #bench
+        }
+
+        fn verify_year(year: usize, dp: DP, store: &InputStore, record: bool, config: &RunConfig) -> std::io::Result<Vec<PartResult>> {
+            #verify
+        }
+
+        fn check_year(year: usize, dp: DP, config: &RunConfig) -> Vec<PartResult> {
+            #check
+        }
+
+        fn year_entries(year: usize) -> &'static [DayEntry] {
+            #entries
+        }
+    }
+    .into()
 }
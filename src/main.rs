@@ -1,59 +1,648 @@
-use advent_core::{get_ydp_and_input, make_year, Selection, Year, DP, YDP};
+use std::env;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use advent_core::{
+    check_answer, default_days, enable_child_processes, expand_inputs, is_input_pattern, make_year,
+    map_ordered, parse_duration, part_statuses, print_bench, print_days, print_inputs,
+    print_results, print_status, print_verify, print_year_changes, serve_child, watch, Answers,
+    BenchConfig, BenchHistory, BenchResult, BenchRun, Comparison, DayEntry, DayListing, InputKind,
+    InputSource, InputStore, NewYearConfig, OutputFormat, PartResult, RunConfig, Selection, Status,
+    Year, CHILD_COMMAND, DP, MAX_DAY, YDP,
+};
+use clap::{Args, Parser, Subcommand};
 use macros::global_runner;
 
-global_runner!(2024, 2025);
+global_runner!();
 
-fn run_ydp(ydp: YDP, input: Option<String>) {
-    let dp = ydp.to_dp();
+/// Run, benchmark, and scaffold Advent of Code solutions
+#[derive(Parser)]
+#[command(name = "advent", version)]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+    /// How to print results: human, json, or csv
+    #[arg(long, global = true, default_value = "human")]
+    format: OutputFormat,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Scaffold a new year crate under `years/`
+    New {
+        /// The year to create
+        year: usize,
+        /// How many days to scaffold, 25 for years before 2025 and 12 after
+        ///
+        /// Days that already exist are kept, so this can add days to an existing year.
+        #[arg(long)]
+        days: Option<usize>,
+        /// Overwrite files that already exist, including solved days
+        #[arg(long)]
+        force: bool,
+        /// List what would be created without writing anything
+        #[arg(long)]
+        dry_run: bool,
+    },
+    /// Solve the selected puzzles
+    #[command(visible_alias = "run")]
+    Solve(RunArgs),
+    /// Benchmark the selected puzzles
+    Bench(BenchArgs),
+    /// Check the selected puzzles against the answers recorded for their stored inputs
+    Verify(VerifyArgs),
+    /// Show which parts are implemented, pass their examples, and match their recorded answers
+    Status(StatusArgs),
+    /// List the selected days and their titles
+    #[command(visible_alias = "list")]
+    Days(DaysArgs),
+    /// Re-run the selected puzzles' examples and input whenever their source, examples, or input change
+    Watch(WatchArgs),
+    /// Solve parts of a day for a parent process, see `advent_core::serve_child`
+    #[command(name = CHILD_COMMAND, hide = true)]
+    SolveChild {
+        year: usize,
+        day: usize,
+        /// Solve the day's examples rather than an input read from stdin
+        #[arg(long)]
+        example: bool,
+        parts: Vec<usize>,
+    },
+}
+
+#[derive(Args)]
+struct RunArgs {
+    #[command(flatten)]
+    puzzles: PuzzleArgs,
+    /// Paths or glob patterns of puzzle inputs, `-` for stdin, `@stored`, `@example[:N]`, or `@text:...`, the stored input is used if omitted
+    ///
+    /// Given several inputs, a single day is run against each and checked against the
+    /// `answers.txt` next to it.
+    input: Vec<String>,
+    /// Always run against the example inputs, ignoring stored inputs
+    #[arg(long, conflicts_with = "input")]
+    examples: bool,
+    #[command(flatten)]
+    limits: LimitArgs,
+}
+
+#[derive(Args)]
+struct PuzzleArgs {
+    /// Puzzles to select as `year[:day[:part]]`, each level takes `*`, `N`, `N-M`, `N-`, `-M`, or a comma separated list
+    #[arg(default_value = "*")]
+    selection: YDP,
+    /// Directory of stored puzzle inputs, laid out as `<year>/day_<day>.txt`
+    #[arg(long, default_value = InputStore::DEFAULT_DIR)]
+    inputs: PathBuf,
+}
+
+#[derive(Args)]
+struct LimitArgs {
+    /// Give up on any part still running after this long, e.g. `30s`, days may set their own
+    #[arg(long, value_parser = parse_duration)]
+    timeout: Option<Duration>,
+    /// Run up to this many days at once, `0` for one per core, benchmarks always run one at a time
+    #[arg(short, long, value_name = "N", default_value_t = 1)]
+    jobs: usize,
+}
 
-    match ydp.year {
-        Selection::All => {
-            run_all_years(&dp, input);
+impl LimitArgs {
+    fn config(&self) -> RunConfig {
+        let jobs = match self.jobs {
+            0 => std::thread::available_parallelism().map_or(1, |n| n.get()),
+            jobs => jobs,
+        };
+        RunConfig {
+            timeout: self.timeout,
+            jobs,
         }
-        Selection::Single(year) => {
-            run_year(year, dp, input.as_deref());
+    }
+}
+
+#[derive(Args)]
+struct BenchArgs {
+    #[command(flatten)]
+    run: RunArgs,
+    /// How long to run each routine before measuring, e.g. `500ms` or `1s`
+    #[arg(long, default_value = "1s", value_parser = parse_duration)]
+    warmup: Duration,
+    /// How long to measure each routine for, e.g. `5s`
+    #[arg(long, default_value = "5s", value_parser = parse_duration)]
+    measure: Duration,
+    /// Measure a fixed number of iterations of each routine instead of for a fixed time
+    #[arg(long, conflicts_with = "measure")]
+    iterations: Option<usize>,
+    /// Save the results as a named baseline to compare later runs against
+    #[arg(long, value_name = "NAME")]
+    baseline: Option<String>,
+    /// Compare the results against a previously saved baseline, exiting with 1 if any part regressed significantly
+    #[arg(long, value_name = "NAME")]
+    compare: Option<String>,
+    /// Directory bench history and baselines are kept in
+    #[arg(long, default_value = BenchHistory::DEFAULT_DIR)]
+    history_dir: PathBuf,
+}
+
+impl BenchArgs {
+    fn config(&self) -> BenchConfig {
+        BenchConfig {
+            warmup: self.warmup,
+            measure: self.measure,
+            iterations: self.iterations,
+            timeout: self.run.limits.timeout,
         }
     }
 }
 
-fn bench_ydp(ydp: YDP, input: Option<String>) {
+#[derive(Args)]
+struct VerifyArgs {
+    #[command(flatten)]
+    puzzles: PuzzleArgs,
+    /// Record the current answer for any part that doesn't have one recorded yet
+    #[arg(long)]
+    record: bool,
+    #[command(flatten)]
+    limits: LimitArgs,
+}
+
+#[derive(Args)]
+struct StatusArgs {
+    #[command(flatten)]
+    puzzles: PuzzleArgs,
+    #[command(flatten)]
+    limits: LimitArgs,
+}
+
+#[derive(Args)]
+struct DaysArgs {
+    /// Days to select as `year[:day]`, each level takes `*`, `N`, `N-M`, `N-`, `-M`, or a comma separated list
+    #[arg(default_value = "*")]
+    selection: YDP,
+    /// Only list days whose title contains this, ignoring case
+    #[arg(long)]
+    title: Option<String>,
+}
+
+#[derive(Args)]
+struct WatchArgs {
+    #[command(flatten)]
+    puzzles: PuzzleArgs,
+    /// Path to a puzzle input, the stored input is used if omitted
+    input: Option<PathBuf>,
+    #[command(flatten)]
+    limits: LimitArgs,
+    /// Check once and exit instead of watching, used for each run after a rebuild
+    #[arg(long, hide = true)]
+    once: bool,
+}
+
+impl RunArgs {
+    fn input_paths(&self) -> Vec<String> {
+        expand_inputs(&self.input).unwrap_or_else(|e| {
+            eprintln!("{e}");
+            std::process::exit(1);
+        })
+    }
+
+    fn read_input(&self) -> Option<InputSource> {
+        let paths = self.input_paths();
+        if paths.len() > 1 {
+            eprintln!("Only one input can be used here, got {}", paths.len());
+            std::process::exit(1);
+        }
+        paths.first().map(|path| load_input_or_exit(path))
+    }
+
+    /// Expand the inputs once, they're a set if several were given or any was a glob pattern,
+    /// even one that matched a single file.
+    fn inputs(&self) -> Inputs {
+        let paths = self.input_paths();
+        if paths.len() > 1 || self.input.iter().any(|i| is_input_pattern(i)) {
+            Inputs::Set(paths)
+        } else {
+            Inputs::Single(paths.first().map(|path| load_input_or_exit(path)))
+        }
+    }
+
+    fn store(&self) -> Option<InputStore> {
+        (!self.examples).then(|| InputStore::new(&self.puzzles.inputs))
+    }
+}
+
+enum Inputs {
+    /// At most one input, the stored input is used if there's none.
+    Single(Option<InputSource>),
+    /// Paths to run a single day against, each checked against the answers recorded next to it.
+    Set(Vec<String>),
+}
+
+fn load_input_or_exit(input: &str) -> InputSource {
+    let source = input.parse::<InputSource>().unwrap_or_else(|e| {
+        eprintln!("{e}");
+        std::process::exit(1);
+    });
+    source.load().unwrap_or_else(|e| {
+        eprintln!("Failed to read input: {e}");
+        std::process::exit(1);
+    })
+}
+
+/// Drop an input that's the same for every day if it'd be run against more than one year.
+fn input_for_years(input: Option<InputSource>, years: &[usize]) -> Option<InputSource> {
+    input.filter(|input| years.len() == 1 || !input.is_fixed())
+}
+
+fn selected_years(ydp: &YDP) -> Vec<usize> {
+    if let Selection::Single(year) = ydp.year {
+        if !YEARS.contains(&year) {
+            eprintln!("Unknown year: {year}");
+            std::process::exit(1);
+        }
+    }
+
+    let years = ydp.year.select(YEARS.iter().copied()).collect::<Vec<_>>();
+    if years.is_empty() {
+        eprintln!("No years match the selection");
+        std::process::exit(1);
+    }
+
+    let has_days = years.iter().any(|&year| {
+        let days = year_entries(year).iter().map(|entry| entry.day);
+        ydp.day.select(days).next().is_some()
+    });
+    if !has_days {
+        eprintln!("No days match the selection");
+        std::process::exit(1);
+    }
+
+    if ydp.part.select(1..=2).next().is_none() {
+        eprintln!("No parts match the selection, days have parts 1 and 2");
+        std::process::exit(1);
+    }
+
+    years
+}
+
+fn run_ydp(
+    ydp: YDP,
+    input: Option<InputSource>,
+    store: Option<InputStore>,
+    config: RunConfig,
+    format: OutputFormat,
+) {
     let dp = ydp.to_dp();
+    let years = selected_years(&ydp);
+    let input = input_for_years(input, &years);
+
+    let results = map_ordered(years, config.jobs, |year| {
+        run_year(year, dp.clone(), input.as_ref(), store.as_ref(), &config)
+    })
+    .concat();
+
+    print_results(&results, format);
+
+    if results
+        .iter()
+        .any(|r| matches!(r.status, Status::Failed | Status::Timeout))
+    {
+        std::process::exit(1);
+    }
+}
+
+fn solve_inputs(ydp: &YDP, paths: &[String], config: RunConfig, format: OutputFormat) {
+    let years = selected_years(ydp);
+    let (&[year], &Selection::Single(day)) = (years.as_slice(), &ydp.day) else {
+        eprintln!("Running several inputs needs a single year and day, like `2024:7`");
+        std::process::exit(1);
+    };
+    let dp = ydp.to_dp();
+
+    let results = map_ordered(paths.to_vec(), config.jobs, |path| {
+        let input = load_input_or_exit(&path);
+        let answers_path = Path::new(&path)
+            .parent()
+            .unwrap_or(Path::new(""))
+            .join(Answers::FILE_NAME);
+        let answers = Answers::load(answers_path).unwrap_or_else(|e| {
+            eprintln!("Failed to load answers for {path}: {e}");
+            std::process::exit(1);
+        });
+
+        run_year(year, dp.clone(), Some(&input), None, &config)
+            .into_iter()
+            .map(|result| {
+                let expected = answers.get(day, result.part).map(str::to_string);
+                let mut result = check_answer(result, expected);
+                result.input_path = Some(path.clone());
+                result
+            })
+            .collect::<Vec<_>>()
+    })
+    .concat();
 
-    match ydp.year {
-        Selection::Single(year) => {
-            bench_year(year, dp, input.as_deref());
+    print_inputs(&results, format);
+
+    if results
+        .iter()
+        .any(|r| matches!(r.status, Status::Fail | Status::Failed | Status::Timeout))
+    {
+        std::process::exit(1);
+    }
+}
+
+fn bench_ydp(
+    ydp: YDP,
+    input: Option<InputSource>,
+    store: Option<InputStore>,
+    args: &BenchArgs,
+    format: OutputFormat,
+) {
+    let dp = ydp.to_dp();
+    let years = selected_years(&ydp);
+    let input = input_for_years(input, &years);
+    let config = args.config();
+    let history = BenchHistory::new(&args.history_dir);
+
+    let compare = args.compare.as_deref().map(|name| {
+        let baseline = history.load_baseline(name).unwrap_or_else(|e| {
+            eprintln!("Failed to load baseline: {e}");
+            std::process::exit(1);
+        });
+        (name, baseline)
+    });
+
+    let results = years
+        .into_iter()
+        .flat_map(|year| bench_year(year, dp.clone(), input.as_ref(), store.as_ref(), &config))
+        .collect::<Vec<_>>();
+
+    let run = BenchRun::new(results);
+    let comparison = compare.map(|(name, baseline)| Comparison::new(name, &baseline, &run));
+
+    print_bench(&run.results, comparison.as_ref(), format);
+
+    if let Err(e) = history.append(&run) {
+        eprintln!("Failed to save bench history: {e}");
+    }
+
+    if let Some(name) = args.baseline.as_deref() {
+        match history.save_baseline(name, &run) {
+            Ok(()) => eprintln!("Saved baseline `{name}`"),
+            Err(e) => eprintln!("Failed to save baseline `{name}`: {e}"),
         }
-        _ => panic!("Cannot Bench All Years"),
+    }
+
+    if comparison.is_some_and(|c| c.regressions() > 0) {
+        std::process::exit(1);
     }
 }
 
-fn main() {
-    let args = std::env::args().skip(1).collect::<Vec<_>>();
+fn verify_ydp(ydp: YDP, store: InputStore, record: bool, config: RunConfig, format: OutputFormat) {
+    let dp = ydp.to_dp();
+    let results = map_ordered(selected_years(&ydp), config.jobs, |year| {
+        verify_year(year, dp.clone(), &store, record, &config).unwrap_or_else(|e| {
+            eprintln!("Failed to verify {year}: {e}");
+            std::process::exit(1);
+        })
+    })
+    .concat();
+
+    print_verify(&results, format);
+
+    if results
+        .iter()
+        .any(|v| matches!(v.status, Status::Fail | Status::Failed | Status::Timeout))
+    {
+        std::process::exit(1);
+    }
+}
+
+fn status_ydp(ydp: YDP, store: InputStore, config: RunConfig, format: OutputFormat) {
+    let dp = ydp.to_dp();
+    let statuses = map_ordered(selected_years(&ydp), config.jobs, |year| {
+        let examples = check_year(year, dp.clone(), &config);
+        let verified = verify_year(year, dp.clone(), &store, false, &config).unwrap_or_else(|e| {
+            eprintln!("Failed to verify {year}: {e}");
+            std::process::exit(1);
+        });
+        part_statuses(&examples, &verified)
+    })
+    .concat();
+
+    print_status(&statuses, format);
+}
+
+fn check_ydp(ydp: YDP, input: Option<&Path>, store: InputStore, config: RunConfig) {
+    let dp = ydp.to_dp();
+    let years = selected_years(&ydp);
+
+    let input = input.filter(|_| years.len() == 1).map(|path| {
+        InputSource::File(path.to_path_buf())
+            .load()
+            .unwrap_or_else(|e| {
+                eprintln!("Failed to read input: {e}");
+                std::process::exit(1);
+            })
+    });
+
+    let examples = map_ordered(years.clone(), config.jobs, |year| {
+        check_year(year, dp.clone(), &config)
+    })
+    .concat();
+
+    println!("Examples:");
+    print_verify(&examples, OutputFormat::Human);
+
+    let real = map_ordered(years, config.jobs, |year| {
+        run_year(year, dp.clone(), input.as_ref(), Some(&store), &config)
+    })
+    .concat()
+    .into_iter()
+    .filter(|r| r.input == InputKind::Real)
+    .collect::<Vec<_>>();
 
-    let command = args.first();
+    if !real.is_empty() {
+        println!("\nInput:");
+        print_results(&real, OutputFormat::Human);
+    }
+}
+
+fn list_days(args: DaysArgs, format: OutputFormat) {
+    let days = selected_years(&args.selection)
+        .into_iter()
+        .flat_map(|year| {
+            let entries = year_entries(year);
+            args.selection
+                .day
+                .select(entries.iter().map(|entry| entry.day))
+                .filter_map(|day| entries.iter().find(|entry| entry.day == day))
+                .filter(|entry| args.title.as_ref().is_none_or(|t| entry.title_matches(t)))
+                .map(move |entry| DayListing::new(year, entry))
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+
+    print_days(&days, format);
+}
 
-    match command {
-        Some(command) => match command.as_str() {
-            "new" => {
-                let year = args.get(1).expect("No year provided");
-                make_year(year);
+/// The source and examples of every selected day, along with the input they run against.
+fn watched_paths(ydp: &YDP, input: Option<&Path>, store: &InputStore) -> Vec<PathBuf> {
+    let mut paths = vec![];
+    for year in selected_years(ydp) {
+        let src = Path::new("years").join(year.to_string()).join("src");
+        for day in ydp
+            .day
+            .select(year_entries(year).iter().map(|entry| entry.day))
+        {
+            let source = src.join(format!("day_{day}.rs"));
+            if !source.exists() {
+                continue;
+            }
+            paths.push(source);
+            paths.push(src.join("examples").join(format!("day_{day}")));
+            if input.is_none() {
+                paths.push(store.path(year, day));
             }
-            "solve" | "run" => {
-                let (ydp, input) = get_ydp_and_input(args[1..].to_vec());
-                run_ydp(ydp, input);
+        }
+    }
+    paths.extend(input.map(Path::to_path_buf));
+    paths
+}
+
+fn watch_ydp(args: WatchArgs) {
+    let store = InputStore::new(&args.puzzles.inputs);
+
+    if args.once {
+        check_ydp(
+            args.puzzles.selection,
+            args.input.as_deref(),
+            store,
+            args.limits.config(),
+        );
+        return;
+    }
+
+    let paths = watched_paths(&args.puzzles.selection, args.input.as_deref(), &store);
+    if paths.is_empty() {
+        eprintln!("No day sources match the selection, run from the root of the repository");
+        std::process::exit(1);
+    }
+
+    // Each run rebuilds and re-runs this command in a fresh process so it picks up the changes
+    let mut child_args = env::args().skip(1).collect::<Vec<_>>();
+    child_args.push("--once".to_string());
+    let profile = if cfg!(debug_assertions) {
+        None
+    } else {
+        Some("--release")
+    };
+
+    let result = watch(&paths, || {
+        print!("\x1b[2J\x1b[H");
+        let _ = io::stdout().flush();
+        let status = std::process::Command::new(env::var("CARGO").unwrap_or("cargo".into()))
+            .args(["run", "--quiet", "--bin", "advent"])
+            .args(profile)
+            .arg("--")
+            .args(&child_args)
+            .status();
+        if let Err(e) = status {
+            eprintln!("Failed to run cargo: {e}");
+        }
+        println!("\nWatching {} paths for changes...", paths.len());
+    });
+
+    if let Err(e) = result {
+        eprintln!("Failed to watch for changes: {e}");
+        std::process::exit(1);
+    }
+}
+
+fn main() {
+    let cli = Cli::parse();
+
+    // Parts with a timeout re-run this binary as a child process so they can be killed
+    if let Ok(exe) = env::current_exe() {
+        enable_child_processes(exe);
+    }
+
+    match cli.command {
+        Command::New {
+            year,
+            days,
+            force,
+            dry_run,
+        } => {
+            let days = days.unwrap_or_else(|| default_days(year));
+            if !(1..=MAX_DAY).contains(&days) {
+                eprintln!("A year has between 1 and {MAX_DAY} days");
+                std::process::exit(1);
             }
-            "bench" => {
-                let (ydp, input) = get_ydp_and_input(args[1..].to_vec());
-                bench_ydp(ydp, input);
+            let config = NewYearConfig { force, dry_run };
+            let changes = make_year(&year.to_string(), days, config);
+            print_year_changes(&changes, config);
+            if changes.iter().any(|c| c.error.is_some()) {
+                std::process::exit(1);
             }
-            _ => {
-                println!("Unknown command: {}", command);
-                println!("Available commands: new, solve, bench");
+        }
+        Command::Solve(args) => match args.inputs() {
+            Inputs::Set(paths) => solve_inputs(
+                &args.puzzles.selection,
+                &paths,
+                args.limits.config(),
+                cli.format,
+            ),
+            Inputs::Single(input) => {
+                let store = args.store();
+                let config = args.limits.config();
+                run_ydp(args.puzzles.selection, input, store, config, cli.format);
             }
         },
-        None => {
-            println!("No command provided");
-            println!("Available commands: new, solve");
+        Command::Bench(args) => {
+            let input = args.run.read_input();
+            let store = args.run.store();
+            bench_ydp(
+                args.run.puzzles.selection.clone(),
+                input,
+                store,
+                &args,
+                cli.format,
+            );
+        }
+        Command::Verify(args) => {
+            verify_ydp(
+                args.puzzles.selection,
+                InputStore::new(args.puzzles.inputs),
+                args.record,
+                args.limits.config(),
+                cli.format,
+            );
+        }
+        Command::Status(args) => {
+            status_ydp(
+                args.puzzles.selection,
+                InputStore::new(args.puzzles.inputs),
+                args.limits.config(),
+                cli.format,
+            );
+        }
+        Command::Days(args) => list_days(args, cli.format),
+        Command::Watch(args) => watch_ydp(args),
+        Command::SolveChild {
+            year,
+            day,
+            example,
+            parts,
+        } => {
+            let Some(entry) = year_entries(year).iter().find(|entry| entry.day == day) else {
+                eprintln!("{year} has no day {day}");
+                std::process::exit(1);
+            };
+            if let Err(e) = serve_child(entry, &parts, example) {
+                eprintln!("Failed to solve for the parent process: {e}");
+                std::process::exit(1);
+            }
         }
     }
 }
@@ -1,30 +1,49 @@
 #[macro_export]
 macro_rules! yippee {
     () => {
-        fn part_2(_: Self::Input) -> Option<String> {
-            Some("🥳".to_string())
+        fn part_2(_: &Self::Input<'_>) -> Option<advent_core::Answer> {
+            Some("🥳".into())
         }
     };
 }
 
+/// A day whose input is a [`crate::grid::Grid`] of `$t` tiles, see `Grid::parse`.
+///
+/// A row that isn't as wide as the first is reported at that row.
 #[macro_export]
 macro_rules! grid_day {
     ($day:literal, $e_1:literal, $e_2:literal, $t:ty) => {
         day_stuff!($day, $e_1, $e_2, utils::grid::Grid<$t>);
 
-        fn parse_input(input: &str) -> Self::Input {
-            Self::Input::parse(input)
+        fn try_parse_input(input: &str) -> Result<Self::Input<'_>, advent_core::InputError> {
+            let width = input.lines().next().map_or(0, |l| l.chars().count());
+            match input.lines().find(|l| l.chars().count() != width) {
+                Some(row) => Err(advent_core::InputError::new(format!(
+                    "expected every row to be {width} tiles wide like the first"
+                ))
+                .at(input, row)),
+                None => Ok(<Self::Input<'_>>::parse(input)),
+            }
         }
     };
 }
 
+/// A day whose input is one `$t` per line, parsed with `FromStr`.
+///
+/// A line that doesn't parse is reported at that line with the parse error's message.
 #[macro_export]
 macro_rules! lines_day {
     ($day:literal, $e_1:literal, $e_2:literal, $t:ty) => {
         day_stuff!($day, $e_1, $e_2, Vec<$t>);
 
-        fn parse_input(input: &str) -> Self::Input {
-            input.lines().map(|l| l.parse().unwrap()).collect()
+        fn try_parse_input(input: &str) -> Result<Self::Input<'_>, advent_core::InputError> {
+            input
+                .lines()
+                .map(|l| {
+                    l.parse()
+                        .map_err(|e| advent_core::InputError::new(format!("{e}")).at(input, l))
+                })
+                .collect()
         }
     };
 }
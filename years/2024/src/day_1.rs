@@ -1,4 +1,4 @@
-use advent_core::{day_stuff, ex_for_day, Day};
+use advent_core::{day_stuff, ex_for_day, Answer, Day, InputError};
 use utils::misc::counts;
 
 pub struct Day1;
@@ -6,8 +6,10 @@ pub struct Day1;
 impl Day for Day1 {
     day_stuff!(1, "11", "31", (Vec<i32>, Vec<i32>));
 
-    fn part_1(input: Self::Input) -> Option<String> {
-        let (mut l, mut r) = input;
+    const TITLE: &'static str = "Historian Hysteria";
+
+    fn part_1(input: &Self::Input<'_>) -> Option<Answer> {
+        let (mut l, mut r) = input.clone();
         l.sort_unstable();
         r.sort_unstable();
 
@@ -16,23 +18,23 @@ impl Day for Day1 {
                 .zip(r)
                 .map(|(l, r)| (l - r).abs())
                 .sum::<i32>()
-                .to_string(),
+                .into(),
         )
     }
 
-    fn part_2(input: Self::Input) -> Option<String> {
+    fn part_2(input: &Self::Input<'_>) -> Option<Answer> {
         let (l, r) = input;
-        let apr = counts(r.into_iter());
+        let apr = counts(r.iter().copied());
         Some(
-            l.into_iter()
-                .map(|l| l as u64 * apr.get(&l).unwrap_or(&0))
+            l.iter()
+                .map(|&l| l as u64 * apr.get(&l).unwrap_or(&0))
                 .sum::<u64>()
-                .to_string(),
+                .into(),
         )
     }
 
-    fn parse_input(input: &str) -> Self::Input {
-        input
+    fn try_parse_input(input: &str) -> Result<Self::Input<'_>, InputError> {
+        Ok(input
             .split("\n")
             .map(|line| {
                 let mut split = line.trim().split_ascii_whitespace();
@@ -40,6 +42,6 @@ impl Day for Day1 {
                 let right = split.next().unwrap().parse::<i32>().unwrap();
                 (left, right)
             })
-            .collect()
+            .collect())
     }
 }
@@ -1,6 +1,6 @@
 use std::collections::HashSet;
 
-use advent_core::{day_stuff, ex_for_day, Day};
+use advent_core::{day_stuff, ex_for_day, Answer, Day, InputError};
 use utils::{dir::CARDINALS, pos::Position};
 
 pub struct Day10;
@@ -24,7 +24,9 @@ fn get_asc(g: &Grid, pos: Position, num: usize) -> impl Iterator<Item = Position
 impl Day for Day10 {
     day_stuff!(10, "36", "81", Grid);
 
-    fn part_1(input: Self::Input) -> Option<String> {
+    const TITLE: &'static str = "Hoof It";
+
+    fn part_1(input: &Self::Input<'_>) -> Option<Answer> {
         let starts = input
             .iter()
             .filter_map(|(p, t)| if t.0 == 0 { Some(p) } else { None });
@@ -37,7 +39,7 @@ impl Day for Day10 {
             while num != 9 {
                 nxt = nxt
                     .into_iter()
-                    .flat_map(|o| get_asc(&input, o, num))
+                    .flat_map(|o| get_asc(input, o, num))
                     .collect::<Vec<_>>();
                 num += 1;
             }
@@ -47,10 +49,10 @@ impl Day for Day10 {
             tot += hsh.len();
         }
 
-        Some(tot.to_string())
+        Some(tot.into())
     }
 
-    fn part_2(input: Self::Input) -> Option<String> {
+    fn part_2(input: &Self::Input<'_>) -> Option<Answer> {
         let starts = input
             .iter()
             .filter_map(|(p, t)| if t.0 == 0 { Some(p) } else { None });
@@ -63,7 +65,7 @@ impl Day for Day10 {
             while num != 9 {
                 nxt = nxt
                     .into_iter()
-                    .flat_map(|o| get_asc(&input, o, num))
+                    .flat_map(|o| get_asc(input, o, num))
                     .collect::<Vec<_>>();
                 num += 1;
             }
@@ -71,10 +73,10 @@ impl Day for Day10 {
             tot += nxt.len();
         }
 
-        Some(tot.to_string())
+        Some(tot.into())
     }
 
-    fn parse_input(input: &str) -> Self::Input {
-        Grid::parse(input.trim())
+    fn try_parse_input(input: &str) -> Result<Self::Input<'_>, InputError> {
+        Ok(Grid::parse(input.trim()))
     }
 }
@@ -1,15 +1,15 @@
 use std::collections::HashMap;
 
-use advent_core::{day_stuff, ex_for_day, Day};
+use advent_core::{day_stuff, ex_for_day, Answer, Day, InputError};
 use utils::num::{num_digits, split_num_once};
 
 pub struct Day11;
 
-pub fn do_blinks(stones: Vec<usize>, blinks: usize) -> usize {
+pub fn do_blinks(stones: &[usize], blinks: usize) -> usize {
     let l = stones.len();
     let mut stone_map = stones
-        .into_iter()
-        .fold(HashMap::with_capacity(l), |mut acc, stone| {
+        .iter()
+        .fold(HashMap::with_capacity(l), |mut acc, &stone| {
             *acc.entry(stone).or_insert(0) += 1;
             acc
         });
@@ -38,19 +38,21 @@ pub fn do_blinks(stones: Vec<usize>, blinks: usize) -> usize {
 impl Day for Day11 {
     day_stuff!(11, "55312", "65601038650482", Vec<usize>);
 
-    fn part_1(input: Self::Input) -> Option<String> {
-        Some(do_blinks(input, 25).to_string())
+    const TITLE: &'static str = "Plutonian Pebbles";
+
+    fn part_1(input: &Self::Input<'_>) -> Option<Answer> {
+        Some(do_blinks(input, 25).into())
     }
 
-    fn part_2(input: Self::Input) -> Option<String> {
-        Some(do_blinks(input, 75).to_string())
+    fn part_2(input: &Self::Input<'_>) -> Option<Answer> {
+        Some(do_blinks(input, 75).into())
     }
 
-    fn parse_input(input: &str) -> Self::Input {
-        input
+    fn try_parse_input(input: &str) -> Result<Self::Input<'_>, InputError> {
+        Ok(input
             .trim()
             .split(" ")
             .map(|n| n.parse::<usize>().unwrap())
-            .collect()
+            .collect())
     }
 }
@@ -1,6 +1,6 @@
 use std::collections::HashSet;
 
-use advent_core::{day_stuff, ex_for_day, Day};
+use advent_core::{day_stuff, ex_for_day, Answer, Day, InputError};
 use utils::{
     dir::{Direction, Movement, CARDINALS},
     pos::Position,
@@ -48,7 +48,7 @@ fn trace_perim(
 }
 
 impl Day for Day12 {
-    fn part_1(input: Self::Input) -> Option<String> {
+    fn part_1(input: &Self::Input<'_>) -> Option<Answer> {
         let mut visited = HashSet::with_capacity(50);
         let mut total = 0;
 
@@ -92,17 +92,17 @@ impl Day for Day12 {
             }
         }
 
-        Some(total.to_string())
+        Some(total.into())
     }
 
     // TODO: Still working on the ""nice"" way of doing this one
-    fn part_2(input: Self::Input) -> Option<String> {
+    fn part_2(input: &Self::Input<'_>) -> Option<Answer> {
         let mut visited = HashSet::with_capacity(50);
         let mut shapes = Vec::<(usize, usize, HashSet<Position>)>::with_capacity(30);
 
         for (pos, c) in input.iter() {
             if !visited.contains(&pos) {
-                let (turns, perimeters) = trace_perim(&input, pos, Direction::East, *c);
+                let (turns, perimeters) = trace_perim(input, pos, Direction::East, *c);
 
                 let mut all_tiles = HashSet::with_capacity(turns);
 
@@ -130,12 +130,12 @@ impl Day for Day12 {
                                     .get(pos2.add(&Direction::East.get_kernel()))
                                     .is_some_and(|c2| c2 != c)
                                 {
-                                    Some(trace_perim(&input, *pos2, Direction::North, *c))
+                                    Some(trace_perim(input, *pos2, Direction::North, *c))
                                 } else if input
                                     .get(pos2.add(&Direction::North.get_kernel()))
                                     .is_some_and(|c2| c2 != c)
                                 {
-                                    Some(trace_perim(&input, *pos2, Direction::East, *c))
+                                    Some(trace_perim(input, *pos2, Direction::East, *c))
                                 } else {
                                     None
                                 };
@@ -158,13 +158,15 @@ impl Day for Day12 {
                 .into_iter()
                 .map(|(area, turns, _)| area * turns)
                 .sum::<usize>()
-                .to_string(),
+                .into(),
         )
     }
 
     day_stuff!(12, "1930", "1206", Grid);
 
-    fn parse_input(input: &str) -> Self::Input {
-        Grid::parse(input.trim())
+    const TITLE: &'static str = "Garden Groups";
+
+    fn try_parse_input(input: &str) -> Result<Self::Input<'_>, InputError> {
+        Ok(Grid::parse(input.trim()))
     }
 }
@@ -1,4 +1,4 @@
-use advent_core::{day_stuff, ex_for_day, Day};
+use advent_core::{day_stuff, ex_for_day, Answer, Day, InputError};
 
 pub struct Day13;
 
@@ -9,53 +9,50 @@ pub struct Machine {
     goal: (isize, isize),
 }
 
+/// Parse a value like `X+94` or `Y=5400`, `raw` being part of `input`.
+fn parse_value(input: &str, raw: &str, prefix: &str) -> Result<isize, InputError> {
+    let num = raw
+        .strip_prefix(prefix)
+        .ok_or_else(|| InputError::new(format!("expected `{prefix}`")).at(input, raw))?;
+    num.parse()
+        .map_err(|_| InputError::new(format!("expected a number after `{prefix}`")).at(input, num))
+}
+
+/// Parse a line like `Button A: X+94, Y+34`, `end` is where to point if the line is missing.
+fn parse_line(
+    input: &str,
+    line: Option<&str>,
+    end: &str,
+    label: &str,
+    sep: char,
+) -> Result<(isize, isize), InputError> {
+    let line = line
+        .ok_or_else(|| InputError::new(format!("expected a `{label}` line")).at(input, end))?;
+    let values = line
+        .strip_prefix(label)
+        .and_then(|l| l.strip_prefix(": "))
+        .ok_or_else(|| InputError::new(format!("expected `{label}: `")).at(input, line))?;
+    let (x, y) = values
+        .split_once(", ")
+        .ok_or_else(|| InputError::new("expected `, ` between X and Y").at(input, values))?;
+
+    Ok((
+        parse_value(input, x, &format!("X{sep}"))?,
+        parse_value(input, y, &format!("Y{sep}"))?,
+    ))
+}
+
 impl Machine {
-    pub fn parse(raw: &str) -> Self {
+    /// Parse a machine from `raw`, one of the blocks of `input`.
+    pub fn parse(input: &str, raw: &str) -> Result<Self, InputError> {
         let mut l = raw.lines();
-        let (ax, ay) = l
-            .next()
-            .unwrap()
-            .split_once(": ")
-            .unwrap()
-            .1
-            .split_once(", ")
-            .unwrap();
-        let (bx, by) = l
-            .next()
-            .unwrap()
-            .split_once(": ")
-            .unwrap()
-            .1
-            .split_once(", ")
-            .unwrap();
-        let (ax, ay) = (
-            ax.split_once("+").unwrap().1.parse::<isize>().unwrap(),
-            ay.split_once("+").unwrap().1.parse::<isize>().unwrap(),
-        );
-
-        let (bx, by) = (
-            bx.split_once("+").unwrap().1.parse::<isize>().unwrap(),
-            by.split_once("+").unwrap().1.parse::<isize>().unwrap(),
-        );
-
-        let (px, py) = l
-            .next()
-            .unwrap()
-            .split_once(": ")
-            .unwrap()
-            .1
-            .split_once(", ")
-            .unwrap();
-        let (px, py) = (
-            px.split_once("=").unwrap().1.parse::<isize>().unwrap(),
-            py.split_once("=").unwrap().1.parse::<isize>().unwrap(),
-        );
-
-        Self {
-            a: (ax, ay),
-            b: (bx, by),
-            goal: (px, py),
-        }
+        let end = &raw[raw.len()..];
+
+        Ok(Self {
+            a: parse_line(input, l.next(), end, "Button A", '+')?,
+            b: parse_line(input, l.next(), end, "Button B", '+')?,
+            goal: parse_line(input, l.next(), end, "Prize", '=')?,
+        })
     }
 
     pub fn presses_needed_for_prizes(&self) -> Option<isize> {
@@ -76,17 +73,21 @@ impl Machine {
 impl Day for Day13 {
     day_stuff!(13, "480", "875318608908", Vec<Machine>);
 
-    fn part_1(input: Self::Input) -> Option<String> {
+    const TITLE: &'static str = "Claw Contraption";
+
+    fn part_1(input: &Self::Input<'_>) -> Option<Answer> {
         Some(
             input
                 .iter()
                 .filter_map(Machine::presses_needed_for_prizes)
                 .sum::<isize>()
-                .to_string(),
+                .into(),
         )
     }
 
-    fn part_2(mut input: Self::Input) -> Option<String> {
+    fn part_2(input: &Self::Input<'_>) -> Option<Answer> {
+        let mut input = input.clone();
+
         input.iter_mut().for_each(|m| {
             m.goal.0 += 10000000000000;
             m.goal.1 += 10000000000000;
@@ -97,11 +98,14 @@ impl Day for Day13 {
                 .iter()
                 .filter_map(Machine::presses_needed_for_prizes)
                 .sum::<isize>()
-                .to_string(),
+                .into(),
         )
     }
 
-    fn parse_input(input: &str) -> Self::Input {
-        input.split("\n\n").map(Machine::parse).collect()
+    fn try_parse_input(input: &str) -> Result<Self::Input<'_>, InputError> {
+        input
+            .split("\n\n")
+            .map(|raw| Machine::parse(input, raw))
+            .collect()
     }
 }
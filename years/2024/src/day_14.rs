@@ -1,6 +1,6 @@
 use std::{cmp::Ordering, collections::HashSet};
 
-use advent_core::{day_stuff, ex_for_day, Day};
+use advent_core::{day_stuff, ex_for_day, Answer, Day, InputError};
 use regex::Regex;
 use utils::{pos::Position, upos};
 
@@ -13,11 +13,13 @@ fn robot_go(pos: Position, vel: Position, times: isize, bounds: (usize, usize))
 impl Day for Day14 {
     day_stuff!(14, "12", "", ((usize, usize), Vec<(Position, Position)>));
 
-    fn part_1((bounds, input): Self::Input) -> Option<String> {
+    const TITLE: &'static str = "Restroom Redoubt";
+
+    fn part_1((bounds, input): &Self::Input<'_>) -> Option<Answer> {
         let times = 100;
         let (ur, ul, ll, lr) = input
-            .into_iter()
-            .map(move |(pos, vel)| robot_go(pos, vel, times, bounds))
+            .iter()
+            .map(move |&(pos, vel)| robot_go(pos, vel, times, *bounds))
             .fold((0, 0, 0, 0), move |mut acc, robo| {
                 let is_upper = match robo.y.cmp(&(bounds.1 as isize / 2)) {
                     Ordering::Equal => None,
@@ -42,21 +44,21 @@ impl Day for Day14 {
                 acc
             });
 
-        Some((ur * ul * ll * lr).to_string())
+        Some((ur * ul * ll * lr).into())
     }
 
-    fn part_2((bounds, input): Self::Input) -> Option<String> {
+    fn part_2((bounds, input): &Self::Input<'_>) -> Option<Answer> {
         let re = Regex::new(include_str!("da_tree.txt")).unwrap();
 
-        if bounds != (101, 103) {
+        if *bounds != (101, 103) {
             // Im to lazy to account for other sizes, womp womp.
-            return Some(String::new());
+            return Some("".into());
         }
 
         for i in 0..i32::MAX {
             let bots = input
                 .iter()
-                .map(move |r| robot_go(r.0, r.1, i as isize, bounds))
+                .map(move |r| robot_go(r.0, r.1, i as isize, *bounds))
                 .collect::<HashSet<_>>();
 
             let hay = (0..bounds.1)
@@ -76,14 +78,14 @@ impl Day for Day14 {
                 .collect::<String>();
 
             if re.is_match(&hay) {
-                return Some(i.to_string());
+                return Some(i.into());
             }
         }
 
         panic!("No Tree Womp Womp")
     }
 
-    fn parse_input(input: &str) -> Self::Input {
+    fn try_parse_input(input: &str) -> Result<Self::Input<'_>, InputError> {
         let (bounds, rest) = input.trim().split_once("\n\n").unwrap();
 
         let mut bounds = bounds.split(',').map(|s| s.parse::<usize>().unwrap());
@@ -113,6 +115,6 @@ impl Day for Day14 {
             })
             .collect();
 
-        ((x, y), input)
+        Ok(((x, y), input))
     }
 }
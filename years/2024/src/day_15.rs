@@ -1,6 +1,6 @@
 use std::collections::{HashMap, HashSet};
 
-use advent_core::{day_stuff, ex_for_day, Day};
+use advent_core::{day_stuff, ex_for_day, Answer, Day, InputError};
 use utils::{
     dir::{Direction, Movement},
     grid::Grid,
@@ -19,13 +19,13 @@ tiles!(Tile, [
     ']' => BoxRight,
 ]);
 
-fn parse_instruction(c: char) -> Direction {
+fn parse_instruction(c: char) -> Option<Direction> {
     match c {
-        '^' => Direction::North,
-        '<' => Direction::West,
-        '>' => Direction::East,
-        'v' => Direction::South,
-        _ => unreachable!("For {c:?}"),
+        '^' => Some(Direction::North),
+        '<' => Some(Direction::West),
+        '>' => Some(Direction::East),
+        'v' => Some(Direction::South),
+        _ => None,
     }
 }
 
@@ -111,25 +111,36 @@ fn gps(pos_map: &PosMap) -> usize {
         .sum()
 }
 
-type Input = (Position, PosMap, Vec<Direction>);
+type Warehouse = (Position, PosMap, Vec<Direction>);
 
-fn actual_parse(input: &str) -> Input {
-    let (map, dirs) = input.trim().split_once("\n\n").unwrap();
+fn actual_parse(input: &str) -> Result<Warehouse, InputError> {
+    let (map, dirs) = input
+        .trim()
+        .split_once("\n\n")
+        .ok_or_else(|| InputError::new("expected a blank line between the map and the moves"))?;
     let dirs = dirs
-        .split('\n')
-        .flat_map(|l| l.chars().map(parse_instruction))
-        .collect();
+        .char_indices()
+        .filter(|(_, c)| *c != '\n')
+        .map(|(i, c)| {
+            parse_instruction(c).ok_or_else(|| {
+                InputError::new("expected a move, one of `^`, `<`, `>`, or `v`")
+                    .at(input, &dirs[i..i + c.len_utf8()])
+            })
+        })
+        .collect::<Result<_, _>>()?;
 
     let grid = Grid::<Tile>::parse(map);
 
-    let robo = grid.find_tile(&Tile::Robot).unwrap();
+    let robo = grid
+        .find_tile(&Tile::Robot)
+        .ok_or_else(|| InputError::new("expected a robot `@` on the map").at(input, map))?;
 
     let pos_map = grid.iter().map(|(pos, tile)| (pos, *tile)).collect();
 
-    (robo, pos_map, dirs)
+    Ok((robo, pos_map, dirs))
 }
 
-fn actual_parse_part_2(input: &str) -> Input {
+fn actual_parse_part_2(input: &str) -> Result<Warehouse, InputError> {
     let replace = input.replace('#', "##");
     let replace = replace.replace('.', "..");
     let replace = replace.replace('O', "[]");
@@ -138,25 +149,32 @@ fn actual_parse_part_2(input: &str) -> Input {
 }
 
 impl Day for Day15 {
-    day_stuff!(15, "10092", "9021");
+    day_stuff!(15, "10092", "9021", (Warehouse, Warehouse));
+
+    const TITLE: &'static str = "Warehouse Woes";
 
-    fn part_1(input: Self::Input) -> Option<String> {
-        let (mut robo, mut pos_map, ins) = actual_parse(&input);
+    fn part_1(input: &Self::Input<'_>) -> Option<Answer> {
+        let (mut robo, mut pos_map, ins) = input.0.clone();
         for i in ins {
             if let Some(new_pos) = movement(robo, i, &mut pos_map) {
                 robo = new_pos;
             }
         }
-        Some(gps(&pos_map).to_string())
+        Some(gps(&pos_map).into())
     }
 
-    fn part_2(input: Self::Input) -> Option<String> {
-        let (mut robo, mut pos_map, ins) = actual_parse_part_2(&input);
+    fn part_2(input: &Self::Input<'_>) -> Option<Answer> {
+        let (mut robo, mut pos_map, ins) = input.1.clone();
         for i in ins {
             if let Some(new_pos) = movement_pt_2(robo, i, &mut pos_map) {
                 robo = new_pos;
             }
         }
-        Some(gps(&pos_map).to_string())
+        Some(gps(&pos_map).into())
+    }
+
+    fn try_parse_input(input: &str) -> Result<Self::Input<'_>, InputError> {
+        // Parse part 1 first so errors point at the input as given rather than the widened map
+        Ok((actual_parse(input)?, actual_parse_part_2(input)?))
     }
 }
@@ -3,7 +3,7 @@ use std::{
     collections::{BinaryHeap, HashMap, HashSet},
 };
 
-use advent_core::{day_stuff, ex_for_day, Day};
+use advent_core::{day_stuff, ex_for_day, Answer, Day, InputError};
 use utils::{
     dir::{Direction, Movement},
     pos::Position,
@@ -43,7 +43,9 @@ impl PartialOrd for DState {
 impl Day for Day16 {
     day_stuff!(16, "11048", "64", Grid);
 
-    fn part_1(input: Self::Input) -> Option<String> {
+    const TITLE: &'static str = "Reindeer Maze";
+
+    fn part_1(input: &Self::Input<'_>) -> Option<Answer> {
         let start_pos = input.find_tile(&Tile::Start).unwrap();
         let end_pos = input.find_tile(&Tile::End).unwrap();
 
@@ -64,7 +66,7 @@ impl Day for Day16 {
         }) = queue.pop()
         {
             if pos == end_pos {
-                return Some(cost.to_string());
+                return Some(cost.into());
             }
 
             if visited
@@ -107,7 +109,7 @@ impl Day for Day16 {
         panic!("No Solution!!!")
     }
 
-    fn part_2(input: Self::Input) -> Option<String> {
+    fn part_2(input: &Self::Input<'_>) -> Option<Answer> {
         let start_pos = input.find_tile(&Tile::Start).unwrap();
         let end_pos = input.find_tile(&Tile::End).unwrap();
 
@@ -132,9 +134,9 @@ impl Day for Day16 {
         }) = queue.pop()
         {
             if pos == end_pos {
-                //return Some(cost.to_string());
+                //return Some(cost.into());
                 if found_min.is_none_or(|s| s == cost) {
-                    all_good.extend(prev.into_iter());
+                    all_good.extend(prev);
                     all_good.insert(end_pos);
                     found_min = Some(cost);
                     continue;
@@ -183,13 +185,13 @@ impl Day for Day16 {
         }
 
         if all_good.contains(&end_pos) {
-            Some(all_good.len().to_string())
+            Some(all_good.len().into())
         } else {
             panic!("No Solution!!!")
         }
     }
 
-    fn parse_input(input: &str) -> Self::Input {
-        Grid::parse(input)
+    fn try_parse_input(input: &str) -> Result<Self::Input<'_>, InputError> {
+        Ok(Grid::parse(input))
     }
 }
@@ -1,4 +1,4 @@
-use advent_core::{day_stuff, ex_for_day, Day};
+use advent_core::{day_stuff, ex_for_day, Answer, Day, InputError};
 
 pub struct Day17;
 
@@ -145,21 +145,25 @@ impl Computer {
 impl Day for Day17 {
     day_stuff!(17, "4,6,3,5,6,3,5,2,1,0", "117440", Computer);
 
-    fn part_1(mut input: Self::Input) -> Option<String> {
+    const TITLE: &'static str = "Chronospatial Computer";
+
+    fn part_1(input: &Self::Input<'_>) -> Option<Answer> {
+        let mut input = input.clone();
+
         let mut ip = 0;
         let mut out = Vec::with_capacity(20);
         while ip < input.instructions.len() - 1 {
             let (next_ip, output) = Instruction::from_slice(&input.instructions[ip..=ip + 1])
                 .execute(ip as u128, &mut input.regs);
             if let Some(v) = output {
-                out.push(v.to_string());
+                out.push(v);
             }
             ip = next_ip as usize;
         }
-        Some(out.join(","))
+        Some(out.into())
     }
 
-    fn part_2(input: Self::Input) -> Option<String> {
+    fn part_2(input: &Self::Input<'_>) -> Option<Answer> {
         let mut possible_a = Vec::with_capacity(1000);
         possible_a.push(0);
         for ins in input.instructions.iter().rev().copied() {
@@ -198,11 +202,11 @@ impl Day for Day17 {
                 })
                 .collect();
         }
-        let ans = possible_a.into_iter().min().map(|v| v.to_string()).unwrap();
-        Some(ans)
+        let ans = possible_a.into_iter().min().unwrap();
+        Some(ans.into())
     }
 
-    fn parse_input(input: &str) -> Self::Input {
-        Computer::parse(input)
+    fn try_parse_input(input: &str) -> Result<Self::Input<'_>, InputError> {
+        Ok(Computer::parse(input))
     }
 }
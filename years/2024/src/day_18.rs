@@ -3,7 +3,7 @@ use std::{
     collections::{BinaryHeap, HashMap},
 };
 
-use advent_core::{day_stuff, ex_for_day, Day};
+use advent_core::{day_stuff, ex_for_day, Answer, Day, InputError};
 use utils::{pos::Position, upos};
 
 pub struct Day18;
@@ -30,7 +30,9 @@ impl PartialOrd for DState {
 impl Day for Day18 {
     day_stuff!(18, "22", "6,1", ((usize, usize), usize, Vec<Position>));
 
-    fn part_1((bounds, fallen, input): Self::Input) -> Option<String> {
+    const TITLE: &'static str = "RAM Run";
+
+    fn part_1((bounds, fallen, input): &Self::Input<'_>) -> Option<Answer> {
         let start_pos = Position::zero();
         let end_pos = upos!(bounds.0 - 1, bounds.1 - 1);
 
@@ -46,7 +48,7 @@ impl Day for Day18 {
 
         while let Some(DState { cost, pos, step_no }) = queue.pop() {
             if pos == end_pos {
-                return Some(cost.to_string());
+                return Some(cost.into());
             }
 
             if dist.get(&pos).is_some_and(|min_score| *min_score < cost) {
@@ -54,8 +56,8 @@ impl Day for Day18 {
             }
 
             for (next_pos, _dir) in pos
-                .adjacents_checked(bounds)
-                .filter(|(p, _)| input.iter().take(fallen).all(|op| op != p))
+                .adjacents_checked(*bounds)
+                .filter(|(p, _)| input.iter().take(*fallen).all(|op| op != p))
             {
                 let next_state = DState {
                     cost: cost + 1,
@@ -72,7 +74,7 @@ impl Day for Day18 {
         panic!("No Path")
     }
 
-    fn part_2((bounds, _, input): Self::Input) -> Option<String> {
+    fn part_2((bounds, _, input): &Self::Input<'_>) -> Option<Answer> {
         for i in 0..input.len() {
             let start_pos = Position::zero();
             let end_pos = upos!(bounds.0 - 1, bounds.1 - 1);
@@ -99,7 +101,7 @@ impl Day for Day18 {
                 }
 
                 for (next_pos, _dir) in pos
-                    .adjacents_checked(bounds)
+                    .adjacents_checked(*bounds)
                     .filter(|(p, _)| input.iter().take(i + 1).all(|op| op != p))
                 {
                     let next_state = DState {
@@ -114,13 +116,13 @@ impl Day for Day18 {
                 }
             }
             if !flag {
-                return Some(format!("{},{}", input[i].x, input[i].y));
+                return Some(vec![input[i].x, input[i].y].into());
             }
         }
         panic!("All paths are possible!")
     }
 
-    fn parse_input(input: &str) -> Self::Input {
+    fn try_parse_input(input: &str) -> Result<Self::Input<'_>, InputError> {
         let (bounds, rest) = input.trim().split_once("\n\n").unwrap();
 
         let mut extra = bounds.split(',').map(|s| s.parse::<usize>().unwrap());
@@ -135,6 +137,6 @@ impl Day for Day18 {
             })
             .collect();
 
-        (bounds, fallen, input)
+        Ok((bounds, fallen, input))
     }
 }
@@ -1,4 +1,4 @@
-use advent_core::{day_stuff, ex_for_day, Day};
+use advent_core::{day_stuff, ex_for_day, Answer, Day, InputError};
 use rayon::prelude::*;
 use std::collections::{HashMap, HashSet};
 
@@ -7,10 +7,14 @@ pub struct Day19;
 impl Day for Day19 {
     day_stuff!(19, "6", "16", (HashSet<String>, Vec<String>));
 
-    fn part_1(input: Self::Input) -> Option<String> {
+    const TITLE: &'static str = "Linen Layout";
+
+    const PARALLEL: bool = false;
+
+    fn part_1(input: &Self::Input<'_>) -> Option<Answer> {
         let (avail, desire) = input;
         let ans = desire
-            .into_iter()
+            .iter()
             .filter(|pat| {
                 let pattern_ends = pat.len() + 1;
                 let mut seen = HashMap::with_capacity(pattern_ends);
@@ -29,10 +33,10 @@ impl Day for Day19 {
             })
             .count();
 
-        Some(ans.to_string())
+        Some(ans.into())
     }
 
-    fn part_2(input: Self::Input) -> Option<String> {
+    fn part_2(input: &Self::Input<'_>) -> Option<Answer> {
         let (avail, desire) = input;
         let ans = desire
             .into_par_iter()
@@ -54,14 +58,14 @@ impl Day for Day19 {
             })
             .sum::<usize>();
 
-        Some(ans.to_string())
+        Some(ans.into())
     }
 
-    fn parse_input(input: &str) -> Self::Input {
+    fn try_parse_input(input: &str) -> Result<Self::Input<'_>, InputError> {
         let (avail, desire) = input.trim().split_once("\n\n").unwrap();
-        (
+        Ok((
             avail.split(", ").map(|s| s.to_string()).collect(),
             desire.split('\n').map(|s| s.to_string()).collect(),
-        )
+        ))
     }
 }
@@ -1,4 +1,4 @@
-use advent_core::{day_stuff, ex_for_day, Day};
+use advent_core::{day_stuff, ex_for_day, Answer, Day, InputError};
 use utils::misc::{all_combos_remove_one, follows_diff_range, FollowRangeResult};
 
 pub struct Day2;
@@ -14,20 +14,22 @@ fn line_valid(line: &[i64]) -> bool {
 impl Day for Day2 {
     day_stuff!(2, "2", "4", Vec<Vec<i64>>);
 
-    fn part_1(input: Self::Input) -> Option<String> {
+    const TITLE: &'static str = "Red-Nosed Reports";
+
+    fn part_1(input: &Self::Input<'_>) -> Option<Answer> {
         Some(
             input
-                .into_iter()
+                .iter()
                 .filter(|v| line_valid(v))
                 .count()
-                .to_string(),
+                .into(),
         )
     }
 
-    fn part_2(input: Self::Input) -> Option<String> {
+    fn part_2(input: &Self::Input<'_>) -> Option<Answer> {
         Some(
             input
-                .into_iter()
+                .iter()
                 .filter(|line| {
                     line_valid(line)
                         || all_combos_remove_one(line).any(|combo| {
@@ -36,18 +38,18 @@ impl Day for Day2 {
                         })
                 })
                 .count()
-                .to_string(),
+                .into(),
         )
     }
 
-    fn parse_input(input: &str) -> Self::Input {
-        input
+    fn try_parse_input(input: &str) -> Result<Self::Input<'_>, InputError> {
+        Ok(input
             .split('\n')
             .map(|l| {
                 l.split_ascii_whitespace()
                     .map(|x| x.parse::<i64>().unwrap())
                     .collect()
             })
-            .collect()
+            .collect())
     }
 }
@@ -1,6 +1,6 @@
 use std::collections::HashMap;
 
-use advent_core::{day_stuff, ex_for_day, Day};
+use advent_core::{day_stuff, ex_for_day, Answer, Day, InputError};
 use utils::{dir::CARDINALS, pos::Position, tiles};
 
 pub struct Day20;
@@ -18,7 +18,9 @@ impl Day for Day20 {
     // Technically it's correct :)
     day_stuff!(20, "0", "0", Grid);
 
-    fn part_1(input: Self::Input) -> Option<String> {
+    const TITLE: &'static str = "Race Condition";
+
+    fn part_1(input: &Self::Input<'_>) -> Option<Answer> {
         let end_pos = input.find_tile(&Tile::End).unwrap();
         let start_pos = input.find_tile(&Tile::Start).unwrap();
         let mut costs = HashMap::with_capacity(100);
@@ -50,10 +52,10 @@ impl Day for Day20 {
 
         let ans = cheat_set.values().filter(|c| **c >= 100).count();
 
-        Some(ans.to_string())
+        Some(ans.into())
     }
 
-    fn part_2(input: Self::Input) -> Option<String> {
+    fn part_2(input: &Self::Input<'_>) -> Option<Answer> {
         let end_pos = input.find_tile(&Tile::End).unwrap();
         let start_pos = input.find_tile(&Tile::Start).unwrap();
         let mut costs = HashMap::with_capacity(100);
@@ -85,10 +87,10 @@ impl Day for Day20 {
 
         let ans = cheat_set.values().filter(|c| **c >= 100).count();
 
-        Some(ans.to_string())
+        Some(ans.into())
     }
 
-    fn parse_input(input: &str) -> Self::Input {
-        Grid::parse(input)
+    fn try_parse_input(input: &str) -> Result<Self::Input<'_>, InputError> {
+        Ok(Grid::parse(input))
     }
 }
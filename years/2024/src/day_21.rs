@@ -1,6 +1,6 @@
 use std::collections::{HashMap, VecDeque};
 
-use advent_core::{day_stuff, ex_for_day, Day};
+use advent_core::{day_stuff, ex_for_day, Answer, Day, InputError};
 use utils::{
     dir::{Direction, CARDINALS},
     pos::Position,
@@ -108,44 +108,46 @@ fn recur_find(
 impl Day for Day21 {
     day_stuff!(21, "126384", "154115708116294", Vec<(usize, Vec<char>)>);
 
-    fn part_1(input: Self::Input) -> Option<String> {
+    const TITLE: &'static str = "Keypad Conundrum";
+
+    fn part_1(input: &Self::Input<'_>) -> Option<Answer> {
         let (num_grid, dir_grid) = pad_grids();
         let (num_best, dir_best) = (find_best_paths(&num_grid), find_best_paths(&dir_grid));
         let mut dp = HashMap::new();
         let ans = input
-            .into_iter()
+            .iter()
             .map(|(num, code)| {
                 let mut robos = vec!['A'; 3];
                 let best_path =
-                    recur_find(&code, 2, true, &mut robos, &num_best, &dir_best, &mut dp);
+                    recur_find(code, 2, true, &mut robos, &num_best, &dir_best, &mut dp);
 
                 best_path * num
             })
             .sum::<usize>();
 
-        Some(ans.to_string())
+        Some(ans.into())
     }
 
-    fn part_2(input: Self::Input) -> Option<String> {
+    fn part_2(input: &Self::Input<'_>) -> Option<Answer> {
         let (num_grid, dir_grid) = pad_grids();
         let (num_best, dir_best) = (find_best_paths(&num_grid), find_best_paths(&dir_grid));
         let mut dp = HashMap::new();
         let ans = input
-            .into_iter()
+            .iter()
             .map(|(num, code)| {
                 let mut robos = vec!['A'; 26];
                 let best_path =
-                    recur_find(&code, 25, true, &mut robos, &num_best, &dir_best, &mut dp);
+                    recur_find(code, 25, true, &mut robos, &num_best, &dir_best, &mut dp);
 
                 best_path * num
             })
             .sum::<usize>();
 
-        Some(ans.to_string())
+        Some(ans.into())
     }
 
-    fn parse_input(input: &str) -> Self::Input {
-        input
+    fn try_parse_input(input: &str) -> Result<Self::Input<'_>, InputError> {
+        Ok(input
             .trim()
             .lines()
             .map(|l| {
@@ -157,6 +159,6 @@ impl Day for Day21 {
                     l.chars().collect(),
                 )
             })
-            .collect()
+            .collect())
     }
 }
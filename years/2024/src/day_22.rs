@@ -1,6 +1,6 @@
 use std::collections::{HashMap, HashSet};
 
-use advent_core::{day_stuff, ex_for_day, Day};
+use advent_core::{day_stuff, ex_for_day, Answer, Day, InputError};
 
 pub struct Day22;
 
@@ -43,19 +43,21 @@ fn get_all_four_unique_changes(init: usize, times: usize) -> HashMap<[isize; 4],
 impl Day for Day22 {
     day_stuff!(22, "37327623", "23", Vec<usize>);
 
-    fn part_1(input: Self::Input) -> Option<String> {
+    const TITLE: &'static str = "Monkey Market";
+
+    fn part_1(input: &Self::Input<'_>) -> Option<Answer> {
         let ans = input
-            .into_iter()
-            .map(|init| secret_n_times(init, 2000))
+            .iter()
+            .map(|&init| secret_n_times(init, 2000))
             .sum::<usize>();
 
-        Some(ans.to_string())
+        Some(ans.into())
     }
 
-    fn part_2(input: Self::Input) -> Option<String> {
+    fn part_2(input: &Self::Input<'_>) -> Option<Answer> {
         let change_to_val = input
-            .into_iter()
-            .map(|init| get_all_four_unique_changes(init, 2000))
+            .iter()
+            .map(|&init| get_all_four_unique_changes(init, 2000))
             .collect::<Vec<_>>();
         let all_changes = change_to_val
             .iter()
@@ -73,14 +75,14 @@ impl Day for Day22 {
             .max()
             .unwrap();
 
-        Some(ans.to_string())
+        Some(ans.into())
     }
 
-    fn parse_input(input: &str) -> Self::Input {
-        input
+    fn try_parse_input(input: &str) -> Result<Self::Input<'_>, InputError> {
+        Ok(input
             .trim()
             .lines()
             .map(|l| l.parse::<usize>().unwrap())
-            .collect()
+            .collect())
     }
 }
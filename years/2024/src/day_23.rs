@@ -1,13 +1,13 @@
 use std::collections::{HashMap, HashSet};
 
-use advent_core::{day_stuff, ex_for_day, Day};
+use advent_core::{day_stuff, ex_for_day, Answer, Day, InputError};
 use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
 
 pub struct Day23;
 
-type Edges = HashMap<String, HashSet<String>>;
+type Edges<'a> = HashMap<&'a str, HashSet<&'a str>>;
 
-fn find_self_in_3<'a>(node: &'a String, edges: &'a Edges) -> Vec<[&'a String; 3]> {
+fn find_self_in_3<'a>(node: &'a str, edges: &Edges<'a>) -> Vec<[&'a str; 3]> {
     edges
         .get(node)
         .unwrap()
@@ -15,7 +15,7 @@ fn find_self_in_3<'a>(node: &'a String, edges: &'a Edges) -> Vec<[&'a String; 3]
         .flat_map(|adj| {
             edges.get(adj).unwrap().iter().filter_map(|sadj| {
                 if edges.get(sadj).unwrap().contains(node) {
-                    let mut v = [node, adj, sadj];
+                    let mut v = [node, *adj, *sadj];
                     v.sort();
                     Some(v)
                 } else {
@@ -27,12 +27,12 @@ fn find_self_in_3<'a>(node: &'a String, edges: &'a Edges) -> Vec<[&'a String; 3]
 }
 
 fn represent<'a>(
-    node: &'a String,
-    friends: HashSet<&'a String>,
-    edges: &'a Edges,
+    node: &'a str,
+    friends: HashSet<&'a str>,
+    edges: &Edges<'a>,
     size: usize,
-    seen: &mut HashSet<&'a String>,
-) -> Option<HashSet<&'a String>> {
+    seen: &mut HashSet<&'a str>,
+) -> Option<HashSet<&'a str>> {
     if friends.len() == size {
         return Some(friends);
     } else if friends.iter().all(|f| seen.contains(f)) {
@@ -47,7 +47,7 @@ fn represent<'a>(
             .is_some_and(|e| friends.iter().all(|f| e.contains(*f)))
         {
             let mut new_friends = friends.clone();
-            new_friends.insert(dep);
+            new_friends.insert(*dep);
             represent(dep, new_friends, edges, size, seen)
         } else {
             None
@@ -56,12 +56,16 @@ fn represent<'a>(
 }
 
 impl Day for Day23 {
-    day_stuff!(23, "7", "co,de,ka,ta", Edges);
+    day_stuff!(23, "7", "co,de,ka,ta", Edges<'a>);
 
-    fn part_1(input: Self::Input) -> Option<String> {
+    const TITLE: &'static str = "LAN Party";
+
+    const PARALLEL: bool = false;
+
+    fn part_1(input: &Self::Input<'_>) -> Option<Answer> {
         let groups = input
             .keys()
-            .flat_map(|k| find_self_in_3(k, &input))
+            .flat_map(|k| find_self_in_3(k, input))
             .collect::<HashSet<_>>();
 
         let ans = groups
@@ -69,10 +73,10 @@ impl Day for Day23 {
             .filter(|g| g.iter().any(|c| c.starts_with('t')))
             .count();
 
-        Some(ans.to_string())
+        Some(ans.into())
     }
 
-    fn part_2(input: Self::Input) -> Option<String> {
+    fn part_2(input: &Self::Input<'_>) -> Option<Answer> {
         let verts = input.keys().cloned().collect::<Vec<_>>();
         let max_group_size = verts
             .iter()
@@ -87,8 +91,8 @@ impl Day for Day23 {
                 verts.par_iter().find_map_any(|node| {
                     represent(
                         node,
-                        HashSet::from_iter([node]),
-                        &input,
+                        HashSet::from_iter([*node]),
+                        input,
                         s,
                         &mut HashSet::with_capacity(s),
                     )
@@ -99,29 +103,17 @@ impl Day for Day23 {
             .collect::<Vec<_>>();
 
         group.sort();
-        let len = group.len();
-        let s = ",".to_string();
-        Some(group.into_iter().intersperse(&s).fold(
-            String::with_capacity(len * 2 + (len - 1)),
-            |mut acc, computer| {
-                acc.push_str(computer.as_str());
-                acc
-            },
-        ))
+        Some(group.into())
     }
 
-    fn parse_input(input: &str) -> Self::Input {
-        input
+    fn try_parse_input(input: &str) -> Result<Self::Input<'_>, InputError> {
+        Ok(input
             .lines()
             .fold(HashMap::with_capacity(50), |mut acc, l| {
                 let (l, r) = l.split_once('-').unwrap();
-                acc.entry(l.to_string())
-                    .or_insert(HashSet::new())
-                    .insert(r.to_string());
-                acc.entry(r.to_string())
-                    .or_insert(HashSet::new())
-                    .insert(l.to_string());
+                acc.entry(l).or_insert(HashSet::new()).insert(r);
+                acc.entry(r).or_insert(HashSet::new()).insert(l);
                 acc
-            })
+            }))
     }
 }
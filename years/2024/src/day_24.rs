@@ -1,17 +1,26 @@
 use std::collections::{HashMap, HashSet, VecDeque};
 
-use advent_core::{day_stuff, ex_for_day, Day};
+use advent_core::{day_stuff, ex_for_day, Answer, Day, InputError};
 
 pub struct Day24;
 
-pub type Wires = HashMap<String, bool>;
-pub type Gates = HashMap<(String, String, String), Gate>;
+pub type Wires<'a> = HashMap<&'a str, bool>;
+pub type Gates<'a> = HashMap<(&'a str, &'a str, Op), Gate<'a>>;
 
-fn find_gate<'a>(gates: &'a Gates, lhs: &str, rhs: &str, op: Op) -> Option<&'a Gate> {
-    gates.get(&(lhs.to_string(), rhs.to_string(), format!("{op:?}")))
+fn find_gate<'a>(gates: &Gates<'a>, lhs: &str, rhs: &str, op: Op) -> Option<Gate<'a>> {
+    gates.get(&(lhs, rhs, op)).copied()
 }
 
-#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+/// Find the wire called `name` by the name the input gives it, so it can be borrowed for as long.
+fn find_target<'a>(gates: &Gates<'a>, name: &str) -> &'a str {
+    gates
+        .values()
+        .map(|g| g.target)
+        .find(|t| *t == name)
+        .expect("No gate outputs to this wire")
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
 pub enum Op {
     And,
     Or,
@@ -28,21 +37,21 @@ impl Op {
     }
 }
 
-#[derive(Debug, Clone)]
-pub struct Gate {
-    lhs: String,
+#[derive(Debug, Clone, Copy)]
+pub struct Gate<'a> {
+    lhs: &'a str,
     op: Op,
-    rhs: String,
-    target: String,
+    rhs: &'a str,
+    target: &'a str,
 }
 
-impl Gate {
-    pub fn parse(raw: &str) -> Self {
+impl<'a> Gate<'a> {
+    pub fn parse(raw: &'a str) -> Self {
         let mut s = raw.split(" ");
-        let lhs = s.next().unwrap().to_string();
+        let lhs = s.next().unwrap();
         let op = s.next().unwrap();
-        let rhs = s.next().unwrap().to_string();
-        let target = s.nth(1).unwrap().to_string();
+        let rhs = s.next().unwrap();
+        let target = s.nth(1).unwrap();
         let op = match op {
             "AND" => Op::And,
             "OR" => Op::Or,
@@ -57,9 +66,9 @@ impl Gate {
         }
     }
 
-    pub fn run(&self, wires: &mut Wires) -> bool {
-        if let (Some(&lhs), Some(&rhs)) = (wires.get(&self.lhs), wires.get(&self.rhs)) {
-            wires.insert(self.target.clone(), self.op.eval(lhs, rhs));
+    pub fn run(&self, wires: &mut Wires<'a>) -> bool {
+        if let (Some(&lhs), Some(&rhs)) = (wires.get(self.lhs), wires.get(self.rhs)) {
+            wires.insert(self.target, self.op.eval(lhs, rhs));
             true
         } else {
             false
@@ -67,15 +76,15 @@ impl Gate {
     }
 }
 
-enum AdderTestResult {
-    Okay(String),
-    SwapNeeded(String, String),
+enum AdderTestResult<'a> {
+    Okay(&'a str),
+    SwapNeeded(&'a str, &'a str),
     CompletelyWrong,
     End,
 }
 
-impl AdderTestResult {
-    fn unwrap_okay(self) -> String {
+impl<'a> AdderTestResult<'a> {
+    fn unwrap_okay(self) -> &'a str {
         match self {
             Self::Okay(s) => s,
             _ => panic!(),
@@ -88,22 +97,20 @@ impl AdderTestResult {
 // x00, y00 -> AND -> [carry output wire]
 // x00, y00 -> XOR -> z00
 //
-fn test_first_adder(gates: &Gates) -> AdderTestResult {
-    let x0 = "x00".to_string();
-    let y0 = "y00".to_string();
-    if let Some(Gate { target, .. }) = find_gate(gates, &x0, &y0, Op::And) {
+fn test_first_adder<'a>(gates: &Gates<'a>) -> AdderTestResult<'a> {
+    if let Some(Gate { target, .. }) = find_gate(gates, "x00", "y00", Op::And) {
         if let Some(Gate {
             target: z_target, ..
-        }) = find_gate(gates, &x0, &y0, Op::Xor)
+        }) = find_gate(gates, "x00", "y00", Op::Xor)
         {
             if z_target == "z00" {
                 if target == "z01" {
                     AdderTestResult::End
                 } else {
-                    AdderTestResult::Okay(target.to_string())
+                    AdderTestResult::Okay(target)
                 }
             } else {
-                AdderTestResult::SwapNeeded(target.to_string(), z_target.to_string())
+                AdderTestResult::SwapNeeded(target, z_target)
             }
         } else {
             AdderTestResult::CompletelyWrong
@@ -134,7 +141,7 @@ fn test_first_adder(gates: &Gates) -> AdderTestResult {
 // #2 & #5, ^
 // #4 & #5, ^
 //
-fn test_adder(num: usize, carry_input: &str, gates: &Gates) -> AdderTestResult {
+fn test_adder<'a>(num: usize, carry_input: &str, gates: &Gates<'a>) -> AdderTestResult<'a> {
     let x = format!("x{num:02}");
     let y = format!("y{num:02}");
     let z = format!("z{num:02}");
@@ -153,48 +160,45 @@ fn test_adder(num: usize, carry_input: &str, gates: &Gates) -> AdderTestResult {
     ) {
         if let Some(Gate {
             target: z_target, ..
-        }) = find_gate(gates, carry_input, xy_xor_target.as_str(), Op::Xor)
+        }) = find_gate(gates, carry_input, xy_xor_target, Op::Xor)
         {
-            if *z_target != z {
+            if z_target != z {
                 // We know gate #3 is pointing to the wrong target, as it should be pointing to z[]
                 // We can now confidently swap z[] and this target
                 // This covers invalid state B, C, and D
-                return AdderTestResult::SwapNeeded(z_target.to_string(), z);
+                return AdderTestResult::SwapNeeded(z_target, find_target(gates, &z));
             }
         } else {
             // We know that gate #2 has an invalid output, and since the only case that involves #2
             // is case A, we know that we're swapped with gate #1, we simply need to return the two
             // targets we already have
-            return AdderTestResult::SwapNeeded(
-                xy_and_target.to_string(),
-                xy_xor_target.to_string(),
-            );
+            return AdderTestResult::SwapNeeded(xy_and_target, xy_xor_target);
         };
 
         // From here we've checked all test cases, we can confidently attempt to find the carry
         // output now
-        let carry_xy_target = &find_gate(gates, xy_xor_target, carry_input, Op::And)
+        let carry_xy_target = find_gate(gates, xy_xor_target, carry_input, Op::And)
             .expect("Failed to find carry_xy_target")
             .target;
-        let carry_out = &find_gate(gates, carry_xy_target, xy_and_target, Op::Or)
+        let carry_out = find_gate(gates, carry_xy_target, xy_and_target, Op::Or)
             .expect("Failed to find carry_out")
             .target;
-        if *carry_out == format!("z{:02}", num + 1) {
+        if carry_out == format!("z{:02}", num + 1) {
             AdderTestResult::End
         } else {
-            AdderTestResult::Okay(carry_out.to_string())
+            AdderTestResult::Okay(carry_out)
         }
     } else {
         AdderTestResult::CompletelyWrong
     }
 }
 
-fn swap_outputs(gates: &mut Gates, out1: &String, out2: &String) {
+fn swap_outputs<'a>(gates: &mut Gates<'a>, out1: &'a str, out2: &'a str) {
     gates.values_mut().for_each(|g| {
-        if g.target == *out1 {
-            g.target = out2.to_string();
-        } else if g.target == *out2 {
-            g.target = out1.to_string();
+        if g.target == out1 {
+            g.target = out2;
+        } else if g.target == out2 {
+            g.target = out1;
         }
     });
 }
@@ -203,18 +207,21 @@ fn swap_outputs(gates: &mut Gates, out1: &String, out2: &String) {
 // z16,tdv,hnd,z09,z23,bks,nrn,tjp
 
 impl Day for Day24 {
-    day_stuff!(24, "4", "bks,hnd,nrn,tdv,tjp,z09,z16,z23", (Wires, Gates));
+    day_stuff!(24, "4", "bks,hnd,nrn,tdv,tjp,z09,z16,z23", (Wires<'a>, Gates<'a>));
+
+    const TITLE: &'static str = "Crossed Wires";
 
-    fn part_1((mut wires, gates): Self::Input) -> Option<String> {
+    fn part_1((wires, gates): &Self::Input<'_>) -> Option<Answer> {
+        let mut wires = wires.clone();
         let mut all_zs = gates
             .values()
             .filter(|g| g.target.starts_with('z'))
-            .map(|g| &g.target)
+            .map(|g| g.target)
             .collect::<Vec<_>>();
         all_zs.sort();
         all_zs.dedup();
 
-        let mut current_zs = HashSet::<&String>::with_capacity(all_zs.len());
+        let mut current_zs = HashSet::<&str>::with_capacity(all_zs.len());
 
         let mut queue = gates.values().collect::<VecDeque<_>>();
 
@@ -223,7 +230,7 @@ impl Day for Day24 {
         {
             if gate.run(&mut wires) {
                 if gate.target.starts_with('z') {
-                    current_zs.insert(&gate.target);
+                    current_zs.insert(gate.target);
                 }
             } else {
                 queue.push_back(gate);
@@ -239,18 +246,19 @@ impl Day for Day24 {
             }
         });
 
-        Some(ans.to_string())
+        Some(ans.into())
     }
 
-    fn part_2((_, mut gates): Self::Input) -> Option<String> {
+    fn part_2((_, gates): &Self::Input<'_>) -> Option<Answer> {
+        let mut gates = gates.clone();
         let mut swapped = Vec::with_capacity(8);
-        let mut current_carry = String::new();
+        let mut current_carry = "";
 
         for i in 0.. {
             let res = if i == 0 {
                 test_first_adder(&gates)
             } else {
-                test_adder(i, &current_carry, &gates)
+                test_adder(i, current_carry, &gates)
             };
 
             match res {
@@ -264,8 +272,8 @@ impl Day for Day24 {
                     panic!("Wrong adder");
                 }
                 AdderTestResult::SwapNeeded(l, r) => {
-                    swap_outputs(&mut gates, &l, &r);
-                    current_carry = test_adder(i, &current_carry, &gates).unwrap_okay();
+                    swap_outputs(&mut gates, l, r);
+                    current_carry = test_adder(i, current_carry, &gates).unwrap_okay();
                     swapped.push(l);
                     swapped.push(r);
                 }
@@ -274,17 +282,17 @@ impl Day for Day24 {
 
         swapped.sort();
 
-        Some(swapped.join(","))
+        Some(swapped.into())
     }
 
-    fn parse_input(input: &str) -> Self::Input {
+    fn try_parse_input(input: &str) -> Result<Self::Input<'_>, InputError> {
         let (inits, gates) = input.trim().split_once("\n\n").unwrap();
 
         let wires = inits
             .lines()
             .map(|l| {
                 let (name, val) = l.split_once(": ").unwrap();
-                (name.to_string(), val == "1")
+                (name, val == "1")
             })
             .collect::<HashMap<_, _>>();
 
@@ -292,17 +300,13 @@ impl Day for Day24 {
             .lines()
             .flat_map(|l| {
                 let gate = Gate::parse(l);
-                let op_str = format!("{:?}", gate.op);
                 [
-                    (
-                        (gate.lhs.clone(), gate.rhs.clone(), op_str.clone()),
-                        gate.clone(),
-                    ),
-                    ((gate.rhs.clone(), gate.lhs.clone(), op_str), gate),
+                    ((gate.lhs, gate.rhs, gate.op), gate),
+                    ((gate.rhs, gate.lhs, gate.op), gate),
                 ]
             })
             .collect::<HashMap<_, _>>();
 
-        (wires, gates)
+        Ok((wires, gates))
     }
 }
@@ -1,6 +1,6 @@
 use std::collections::HashSet;
 
-use advent_core::{day_stuff, ex_for_day, Day};
+use advent_core::{day_stuff, ex_for_day, Answer, Day, InputError};
 use utils::{tiles, upos};
 
 pub struct Day25;
@@ -15,23 +15,25 @@ type Grid = utils::grid::Grid<Tile>;
 impl Day for Day25 {
     day_stuff!(25, "3", "🥳", (HashSet<[u8; 5]>, HashSet<[u8; 5]>));
 
-    fn part_1((locks, keys): Self::Input) -> Option<String> {
+    const TITLE: &'static str = "Code Chronicle";
+
+    fn part_1((locks, keys): &Self::Input<'_>) -> Option<Answer> {
         let ans = locks
-            .into_iter()
+            .iter()
             .flat_map(|l| {
                 keys.iter()
                     .filter(move |k| l.iter().zip(k.iter()).all(|(l, k)| *k <= (5 - *l)))
             })
             .count();
 
-        Some(ans.to_string())
+        Some(ans.into())
     }
 
-    fn part_2(_input: Self::Input) -> Option<String> {
-        Some("🥳".to_string())
+    fn part_2(_input: &Self::Input<'_>) -> Option<Answer> {
+        Some("🥳".into())
     }
 
-    fn parse_input(input: &str) -> Self::Input {
+    fn try_parse_input(input: &str) -> Result<Self::Input<'_>, InputError> {
         let mut locks = HashSet::new();
         let mut keys = HashSet::new();
 
@@ -50,6 +52,6 @@ impl Day for Day25 {
             }
         }
 
-        (locks, keys)
+        Ok((locks, keys))
     }
 }
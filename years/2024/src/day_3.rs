@@ -1,4 +1,4 @@
-use advent_core::{day_stuff, ex_for_day, Day};
+use advent_core::{day_stuff, ex_for_day, Answer, Day};
 use regex::RegexBuilder;
 
 pub struct Day3;
@@ -18,17 +18,19 @@ fn re_do(i: &str) -> u64 {
 impl Day for Day3 {
     day_stuff!(3, "161", "48");
 
-    fn part_1(input: Self::Input) -> Option<String> {
-        Some(re_do(&input).to_string())
+    const TITLE: &'static str = "Mull It Over";
+
+    fn part_1(input: &Self::Input<'_>) -> Option<Answer> {
+        Some(re_do(input).into())
     }
 
-    fn part_2(input: Self::Input) -> Option<String> {
+    fn part_2(input: &Self::Input<'_>) -> Option<Answer> {
         Some(
             input
                 .split("do()")
                 .map(|v| re_do(v.split("don't()").nth(0).unwrap()))
                 .sum::<u64>()
-                .to_string(),
+                .into(),
         )
     }
 }
@@ -1,4 +1,4 @@
-use advent_core::{day_stuff, ex_for_day, Day};
+use advent_core::{day_stuff, ex_for_day, Answer, Day, InputError};
 use utils::{
     dir::{Movement, ALL_8},
     grid::Grid,
@@ -24,7 +24,9 @@ fn check_diag_good(grid: &Grid<char>, pos: Position, kerns: &[impl Movement]) ->
 impl Day for Day4 {
     day_stuff!(4, "18", "9", Grid::<char>);
 
-    fn part_1(input: Self::Input) -> Option<String> {
+    const TITLE: &'static str = "Ceres Search";
+
+    fn part_1(input: &Self::Input<'_>) -> Option<Answer> {
         let ans = input
             .iter()
             .map(|(pos, c)| {
@@ -50,22 +52,22 @@ impl Day for Day4 {
                 }
             })
             .sum::<usize>();
-        Some(ans.to_string())
+        Some(ans.into())
     }
 
-    fn part_2(input: Self::Input) -> Option<String> {
+    fn part_2(input: &Self::Input<'_>) -> Option<Answer> {
         let ans = input
             .iter()
             .filter(|(pos, c)| {
                 **c == 'A'
-                    && check_diag_good(&input, *pos, &PART_2_FORWARD_DIAG)
-                    && check_diag_good(&input, *pos, &PART_2_BACKWARD_DIAG)
+                    && check_diag_good(input, *pos, &PART_2_FORWARD_DIAG)
+                    && check_diag_good(input, *pos, &PART_2_BACKWARD_DIAG)
             })
             .count();
-        Some(ans.to_string())
+        Some(ans.into())
     }
 
-    fn parse_input(input: &str) -> Self::Input {
-        Grid::parse(input)
+    fn try_parse_input(input: &str) -> Result<Self::Input<'_>, InputError> {
+        Ok(Grid::parse(input))
     }
 }
@@ -1,6 +1,6 @@
 use std::collections::{HashMap, HashSet};
 
-use advent_core::{day_stuff, ex_for_day, Day};
+use advent_core::{day_stuff, ex_for_day, Answer, Day, InputError};
 
 pub struct Day5;
 
@@ -53,25 +53,28 @@ impl Rules {
 impl Day for Day5 {
     day_stuff!(5, "143", "123", (Rules, Vec<Vec<i64>>));
 
-    fn part_1(input: Self::Input) -> Option<String> {
+    const TITLE: &'static str = "Print Queue";
+
+    fn part_1(input: &Self::Input<'_>) -> Option<Answer> {
         let (rules, updates) = input;
         let ans = updates
-            .into_iter()
+            .iter()
             .filter_map(|up| {
-                if rules.good(&up) {
+                if rules.good(up) {
                     Some(up[up.len() / 2])
                 } else {
                     None
                 }
             })
             .sum::<i64>();
-        Some(ans.to_string())
+        Some(ans.into())
     }
 
-    fn part_2(input: Self::Input) -> Option<String> {
+    fn part_2(input: &Self::Input<'_>) -> Option<Answer> {
         let (rules, updates) = input;
         let ans = updates
-            .into_iter()
+            .iter()
+            .cloned()
             .filter_map(|mut up| {
                 if !rules.good(&up) {
                     rules.sort_to_rules(&mut up);
@@ -81,22 +84,22 @@ impl Day for Day5 {
                 }
             })
             .sum::<i64>();
-        Some(ans.to_string())
+        Some(ans.into())
     }
 
-    fn parse_input(input: &str) -> Self::Input {
+    fn try_parse_input(input: &str) -> Result<Self::Input<'_>, InputError> {
         let (rules, updates) = input.split_once("\n\n").unwrap();
         let mut rules_o = Rules(HashMap::with_capacity(50));
         for l in rules.lines() {
             let (lhs, rhs) = l.split_once('|').unwrap();
             rules_o.take(lhs.parse().unwrap(), rhs.parse().unwrap());
         }
-        (
+        Ok((
             rules_o,
             updates
                 .split('\n')
                 .map(|l| l.split(',').map(|x| x.parse().unwrap()).collect())
                 .collect(),
-        )
+        ))
     }
 }
@@ -1,6 +1,6 @@
 use std::collections::HashSet;
 
-use advent_core::{day_stuff, ex_for_day, Day};
+use advent_core::{day_stuff, ex_for_day, Answer, Day, InputError};
 use utils::{dir::Direction, pos::Position, prelude::GridCursor, tiles};
 
 pub struct Day6;
@@ -42,7 +42,9 @@ fn crawl_grid_with_obs(mut curs: GridCursor<Tile, Direction>, obs_pos: Position)
 impl Day for Day6 {
     day_stuff!(6, "41", "6", Grid);
 
-    fn part_1(input: Self::Input) -> Option<String> {
+    const TITLE: &'static str = "Guard Gallivant";
+
+    fn part_1(input: &Self::Input<'_>) -> Option<Answer> {
         let mut curs = input
             .cursor_at_tile(&Tile::GuardStart, Direction::North)
             .unwrap();
@@ -61,10 +63,10 @@ impl Day for Day6 {
         // While let means we'll be missing one
         visited.insert(curs.pos);
 
-        Some(visited.len().to_string())
+        Some(visited.len().into())
     }
 
-    fn part_2(input: Self::Input) -> Option<String> {
+    fn part_2(input: &Self::Input<'_>) -> Option<Answer> {
         let mut curs = input
             .cursor_at_tile(&Tile::GuardStart, Direction::North)
             .unwrap();
@@ -88,11 +90,11 @@ impl Day for Day6 {
             }
         }
 
-        Some(obs.len().to_string())
+        Some(obs.len().into())
     }
 
-    fn parse_input(input: &str) -> Self::Input {
-        Grid::parse(input)
+    fn try_parse_input(input: &str) -> Result<Self::Input<'_>, InputError> {
+        Ok(Grid::parse(input))
     }
 }
 
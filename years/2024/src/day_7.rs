@@ -1,13 +1,15 @@
-use advent_core::{day_stuff, ex_for_day, Day};
+use advent_core::{day_stuff, ex_for_day, Answer, Day, InputError};
 
 pub struct Day7;
 
 impl Day for Day7 {
     day_stuff!(7, "3749", "11387", Vec<(i64, Vec<i64>)>);
 
-    fn part_1(input: Self::Input) -> Option<String> {
+    const TITLE: &'static str = "Bridge Repair";
+
+    fn part_1(input: &Self::Input<'_>) -> Option<Answer> {
         let ans = input
-            .into_iter()
+            .iter()
             .filter_map(|(target, operands)| {
                 let ps: u64 = 1 << (operands.len() - 1);
                 for p in 0..=ps {
@@ -20,20 +22,20 @@ impl Day for Day7 {
                             acc + *e
                         }
                     });
-                    if res == target {
-                        return Some(target);
+                    if res == *target {
+                        return Some(*target);
                     }
                 }
                 None
             })
             .sum::<i64>();
 
-        Some(ans.to_string())
+        Some(ans.into())
     }
 
-    fn part_2(input: Self::Input) -> Option<String> {
+    fn part_2(input: &Self::Input<'_>) -> Option<Answer> {
         let ans = input
-            .into_iter()
+            .iter()
             .filter_map(|(target, operands)| {
                 let ps: u64 = 3_u64.pow((operands.len() - 1) as u32);
                 for p in 0..=ps {
@@ -49,19 +51,19 @@ impl Day for Day7 {
                             acc + *e
                         }
                     });
-                    if res == target {
-                        return Some(target);
+                    if res == *target {
+                        return Some(*target);
                     }
                 }
                 None
             })
             .sum::<i64>();
 
-        Some(ans.to_string())
+        Some(ans.into())
     }
 
-    fn parse_input(input: &str) -> Self::Input {
-        input
+    fn try_parse_input(input: &str) -> Result<Self::Input<'_>, InputError> {
+        Ok(input
             .lines()
             .map(|l| {
                 let (eq, rest) = l.split_once(": ").unwrap();
@@ -70,6 +72,6 @@ impl Day for Day7 {
                     rest.split(" ").map(|x| x.parse().unwrap()).collect(),
                 )
             })
-            .collect()
+            .collect())
     }
 }
@@ -1,6 +1,6 @@
 use std::collections::HashSet;
 
-use advent_core::{day_stuff, ex_for_day, Day};
+use advent_core::{day_stuff, ex_for_day, Answer, Day, InputError};
 use utils::tiles;
 
 pub struct Day8;
@@ -18,7 +18,9 @@ type Grid = utils::grid::Grid<Tile>;
 impl Day for Day8 {
     day_stuff!(8, "14", "34", Grid);
 
-    fn part_1(input: Self::Input) -> Option<String> {
+    const TITLE: &'static str = "Resonant Collinearity";
+
+    fn part_1(input: &Self::Input<'_>) -> Option<Answer> {
         let all_antennas = input
             .iter()
             .filter_map(|(pos, t)| match *t {
@@ -48,10 +50,10 @@ impl Day for Day8 {
                 })
         }
 
-        Some(anti_nodes.len().to_string())
+        Some(anti_nodes.len().into())
     }
 
-    fn part_2(input: Self::Input) -> Option<String> {
+    fn part_2(input: &Self::Input<'_>) -> Option<Answer> {
         let all_antennas = input
             .iter()
             .filter_map(|(pos, t)| match *t {
@@ -85,10 +87,10 @@ impl Day for Day8 {
                 })
         }
 
-        Some(anti_nodes.len().to_string())
+        Some(anti_nodes.len().into())
     }
 
-    fn parse_input(input: &str) -> Self::Input {
-        Grid::parse(input)
+    fn try_parse_input(input: &str) -> Result<Self::Input<'_>, InputError> {
+        Ok(Grid::parse(input))
     }
 }
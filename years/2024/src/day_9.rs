@@ -1,4 +1,4 @@
-use advent_core::{day_stuff, ex_for_day, Day};
+use advent_core::{day_stuff, ex_for_day, Answer, Day, InputError};
 
 pub struct Day9;
 
@@ -102,7 +102,11 @@ impl Disk {
 impl Day for Day9 {
     day_stuff!(9, "1928", "2858", Disk);
 
-    fn part_1(mut input: Self::Input) -> Option<String> {
+    const TITLE: &'static str = "Disk Fragmenter";
+
+    fn part_1(input: &Self::Input<'_>) -> Option<Answer> {
+        let mut input = input.clone();
+
         let mut i = input.data.len() - 1;
         loop {
             if let (Block::File(_), _) = input.data[i] {
@@ -119,10 +123,12 @@ impl Day for Day9 {
             }
         }
 
-        Some(input.checksum().to_string())
+        Some(input.checksum().into())
     }
 
-    fn part_2(mut input: Self::Input) -> Option<String> {
+    fn part_2(input: &Self::Input<'_>) -> Option<Answer> {
+        let mut input = input.clone();
+
         let mut i = input.data.len() - 1;
 
         loop {
@@ -142,10 +148,10 @@ impl Day for Day9 {
             }
         }
 
-        Some(input.checksum().to_string())
+        Some(input.checksum().into())
     }
 
-    fn parse_input(input: &str) -> Self::Input {
-        Disk::parse(input.trim())
+    fn try_parse_input(input: &str) -> Result<Self::Input<'_>, InputError> {
+        Ok(Disk::parse(input.trim()))
     }
 }
@@ -1,5 +1,3 @@
-#![feature(iter_intersperse)]
-
 use macros::year;
 
 year!(2024);
@@ -1,4 +1,4 @@
-use advent_core::{day_stuff, ex_for_day, Day};
+use advent_core::{day_stuff, ex_for_day, Answer, Day, InputError};
 
 pub struct Day1;
 
@@ -15,7 +15,7 @@ pub struct Rot {
 }
 
 impl Day for Day1 {
-    fn part_1(input: Self::Input) -> Option<String> {
+    fn part_1(input: &Self::Input<'_>) -> Option<Answer> {
         let mut curr = 50;
         let mut at_0 = 0;
         for rot in input {
@@ -30,10 +30,10 @@ impl Day for Day1 {
             }
         }
 
-        Some(at_0.to_string())
+        Some(at_0.into())
     }
 
-    fn part_2(input: Self::Input) -> Option<String> {
+    fn part_2(input: &Self::Input<'_>) -> Option<Answer> {
         let mut curr = 50;
         let mut at_0 = 0;
         for rot in input {
@@ -50,13 +50,15 @@ impl Day for Day1 {
             curr = (curr + amnt).rem_euclid(100);
         }
 
-        Some(at_0.to_string())
+        Some(at_0.into())
     }
 
     day_stuff!(1, "3", "6", Vec<Rot>);
 
-    fn parse_input(input: &str) -> Self::Input {
-        input
+    const TITLE: &'static str = "Secret Entrance";
+
+    fn try_parse_input(input: &str) -> Result<Self::Input<'_>, InputError> {
+        Ok(input
             .lines()
             .map(|l| {
                 let (dir, amnt) = l.split_at(1);
@@ -65,6 +67,6 @@ impl Day for Day1 {
                     amnt: amnt.parse().unwrap(),
                 }
             })
-            .collect()
+            .collect())
     }
 }
@@ -1,6 +1,6 @@
 use std::collections::VecDeque;
 
-use advent_core::{day_stuff, ex_for_day, Day};
+use advent_core::{day_stuff, ex_for_day, Answer, Day, InputError};
 use rayon::iter::{IntoParallelIterator, ParallelIterator};
 
 use good_lp::*;
@@ -17,7 +17,11 @@ pub struct Machine {
 impl Day for Day10 {
     day_stuff!(10, "7", "33", Vec<Machine>);
 
-    fn part_1(input: Self::Input) -> Option<String> {
+    const TITLE: &'static str = "Factory";
+
+    const PARALLEL: bool = false;
+
+    fn part_1(input: &Self::Input<'_>) -> Option<Answer> {
         let ans = input
             .into_par_iter()
             .map(|mach| {
@@ -46,10 +50,10 @@ impl Day for Day10 {
             })
             .sum::<usize>();
 
-        Some(ans.to_string())
+        Some(ans.into())
     }
 
-    fn part_2(input: Self::Input) -> Option<String> {
+    fn part_2(input: &Self::Input<'_>) -> Option<Answer> {
         let ans = input
             .into_par_iter()
             .map(|mach| {
@@ -71,7 +75,7 @@ impl Day for Day10 {
                     }
                 }
 
-                for (e, j) in exprs.into_iter().zip(mach.target_counters) {
+                for (e, &j) in exprs.into_iter().zip(&mach.target_counters) {
                     problem.add_constraint(e.eq(j as f64));
                 }
 
@@ -84,11 +88,11 @@ impl Day for Day10 {
             })
             .sum::<usize>();
 
-        Some(ans.to_string())
+        Some(ans.into())
     }
 
-    fn parse_input(input: &str) -> Self::Input {
-        input
+    fn try_parse_input(input: &str) -> Result<Self::Input<'_>, InputError> {
+        Ok(input
             .lines()
             .map(|l| {
                 let split = l.split(' ').collect::<Vec<_>>();
@@ -124,6 +128,6 @@ impl Day for Day10 {
                     target_counters,
                 }
             })
-            .collect()
+            .collect())
     }
 }
@@ -1,6 +1,6 @@
 use std::collections::HashMap;
 
-use advent_core::{day_stuff, ex_for_day, Day};
+use advent_core::{day_stuff, ex_for_day, Answer, Day, InputError};
 
 pub struct Day11;
 
@@ -63,28 +63,30 @@ fn all_paths_to_out_constrained<'a>(
 impl Day for Day11 {
     day_stuff!(11, "5", "2", Graph);
 
-    fn part_1(input: Self::Input) -> Option<String> {
+    const TITLE: &'static str = "Reactor";
+
+    fn part_1(input: &Self::Input<'_>) -> Option<Answer> {
         let mut seen = HashMap::with_capacity(input.len());
         let start = "you".to_string();
-        let ans = all_paths_to_out(&start, &input, &mut seen);
-        Some(ans.to_string())
+        let ans = all_paths_to_out(&start, input, &mut seen);
+        Some(ans.into())
     }
 
-    fn part_2(input: Self::Input) -> Option<String> {
+    fn part_2(input: &Self::Input<'_>) -> Option<Answer> {
         let mut seen = HashMap::with_capacity(input.len());
         let start = "svr".to_string();
-        let ans = all_paths_to_out_constrained(&start, false, false, &input, &mut seen);
-        Some(ans.to_string())
+        let ans = all_paths_to_out_constrained(&start, false, false, input, &mut seen);
+        Some(ans.into())
     }
 
-    fn parse_input(input: &str) -> Self::Input {
-        input
+    fn try_parse_input(input: &str) -> Result<Self::Input<'_>, InputError> {
+        Ok(input
             .lines()
             .map(|l| {
                 let (k, v) = l.split_once(':').unwrap();
                 let v = v.trim().split(' ').map(str::to_string).collect::<Vec<_>>();
                 (k.to_string(), v)
             })
-            .collect()
+            .collect())
     }
 }
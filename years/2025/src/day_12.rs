@@ -1,4 +1,4 @@
-use advent_core::{day_stuff, ex_for_day, Day};
+use advent_core::{day_stuff, ex_for_day, Answer, Day, InputError};
 use rayon::iter::{IntoParallelIterator, ParallelIterator};
 use utils::{grid::Grid, pos::Position, tiles, upos, yippee};
 
@@ -104,10 +104,15 @@ fn solve(shapes: &Vec<[Vec<Position>; 4]>, grid: &mut Present, targets: &mut Vec
 impl Day for Day12 {
     day_stuff!(12, "2", "🥳", Input);
 
-    fn part_1((shapes, targets): Self::Input) -> Option<String> {
+    const TITLE: &'static str = "Christmas Tree Farm";
+
+    const PARALLEL: bool = false;
+
+    fn part_1((shapes, targets): &Self::Input<'_>) -> Option<Answer> {
         let ans = targets
             .into_par_iter()
-            .filter_map(|((x, y), mut avail)| {
+            .filter_map(|&((x, y), ref avail)| {
+                let mut avail = avail.clone();
                 let area_needed = shapes
                     .iter()
                     .enumerate()
@@ -117,7 +122,7 @@ impl Day for Day12 {
                     return None;
                 }
                 let mut grid = Grid::new(vec![vec![Tile::Empty; x]; y]);
-                let can_fit = solve(&shapes, &mut grid, &mut avail);
+                let can_fit = solve(shapes, &mut grid, &mut avail);
                 if can_fit {
                     Some(0)
                 } else {
@@ -126,12 +131,12 @@ impl Day for Day12 {
             })
             .count();
 
-        Some(ans.to_string())
+        Some(ans.into())
     }
 
     yippee!();
 
-    fn parse_input(input: &str) -> Self::Input {
+    fn try_parse_input(input: &str) -> Result<Self::Input<'_>, InputError> {
         let sections = input.split("\n\n").collect::<Vec<_>>();
         let shapes = sections
             .iter()
@@ -171,6 +176,6 @@ impl Day for Day12 {
             })
             .collect();
 
-        (shapes, targets)
+        Ok((shapes, targets))
     }
 }
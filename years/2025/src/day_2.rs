@@ -1,6 +1,6 @@
 use std::ops::RangeInclusive;
 
-use advent_core::{day_stuff, ex_for_day, Day};
+use advent_core::{day_stuff, ex_for_day, Answer, Day, InputError};
 use utils::num::{num_digits, split_num_at, split_num_once};
 
 pub struct Day2;
@@ -8,25 +8,27 @@ pub struct Day2;
 impl Day for Day2 {
     day_stuff!(2, "1227775554", "4174379265", Vec<RangeInclusive<usize>>);
 
-    fn part_1(input: Self::Input) -> Option<String> {
+    const TITLE: &'static str = "Gift Shop";
+
+    fn part_1(input: &Self::Input<'_>) -> Option<Answer> {
         let ans = input
-            .into_iter()
+            .iter()
             .flat_map(|r| {
-                r.into_iter().filter(|x| {
+                r.clone().filter(|x| {
                     let (l, r) = split_num_once(*x);
                     l == r
                 })
             })
             .sum::<usize>();
 
-        Some(ans.to_string())
+        Some(ans.into())
     }
 
-    fn part_2(input: Self::Input) -> Option<String> {
+    fn part_2(input: &Self::Input<'_>) -> Option<Answer> {
         let ans = input
-            .into_iter()
+            .iter()
             .flat_map(|r| {
-                r.into_iter().filter(|x| {
+                r.clone().filter(|x| {
                     let digs = num_digits(*x);
                     (2..=digs).filter(|n| digs.is_multiple_of(*n)).any(|n| {
                         let mut parts = Vec::with_capacity(n);
@@ -46,16 +48,16 @@ impl Day for Day2 {
             })
             .sum::<usize>();
 
-        Some(ans.to_string())
+        Some(ans.into())
     }
 
-    fn parse_input(input: &str) -> Self::Input {
-        input
+    fn try_parse_input(input: &str) -> Result<Self::Input<'_>, InputError> {
+        Ok(input
             .split(',')
             .map(|r| {
                 let (l, r) = r.split_once('-').unwrap();
                 (l.trim().parse::<usize>().unwrap())..=(r.trim().parse::<usize>().unwrap())
             })
-            .collect()
+            .collect())
     }
 }
@@ -1,13 +1,15 @@
-use advent_core::{day_stuff, ex_for_day, Day};
+use advent_core::{day_stuff, ex_for_day, Answer, Day, InputError};
 
 pub struct Day3;
 
 impl Day for Day3 {
     day_stuff!(3, "357", "3121910778619", Vec<Vec<usize>>);
 
-    fn part_1(input: Self::Input) -> Option<String> {
+    const TITLE: &'static str = "Lobby";
+
+    fn part_1(input: &Self::Input<'_>) -> Option<Answer> {
         let ans = input
-            .into_iter()
+            .iter()
             .map(|bank| {
                 let (largest_idx, largest) = bank
                     .iter()
@@ -17,18 +19,18 @@ impl Day for Day3 {
                     .max_by(|(_, x), (_, y)| (**x).cmp(*y))
                     .map(|(i, x)| (i, *x))
                     .unwrap();
-                let rest = bank.into_iter().skip(largest_idx + 1);
+                let rest = bank.iter().skip(largest_idx + 1);
                 let next_largest = rest.max().unwrap();
                 largest * 10 + next_largest
             })
             .sum::<usize>();
 
-        Some(ans.to_string())
+        Some(ans.into())
     }
 
-    fn part_2(input: Self::Input) -> Option<String> {
+    fn part_2(input: &Self::Input<'_>) -> Option<Answer> {
         let ans = input
-            .into_iter()
+            .iter()
             .map(|bank| {
                 let mut num = 0;
                 let mut start = 0;
@@ -53,11 +55,11 @@ impl Day for Day3 {
             })
             .sum::<usize>();
 
-        Some(ans.to_string())
+        Some(ans.into())
     }
 
-    fn parse_input(input: &str) -> Self::Input {
-        input
+    fn try_parse_input(input: &str) -> Result<Self::Input<'_>, InputError> {
+        Ok(input
             .lines()
             .map(|l| {
                 l.trim()
@@ -65,6 +67,6 @@ impl Day for Day3 {
                     .map(|c| c.to_digit(10).unwrap() as usize)
                     .collect()
             })
-            .collect()
+            .collect())
     }
 }
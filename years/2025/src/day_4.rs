@@ -1,4 +1,4 @@
-use advent_core::{day_stuff, ex_for_day, Day};
+use advent_core::{day_stuff, ex_for_day, Answer, Day, InputError};
 use utils::{dir::ALL_8, tiles};
 
 pub struct Day4;
@@ -13,7 +13,9 @@ type Grid = utils::grid::Grid<Tile>;
 impl Day for Day4 {
     day_stuff!(4, "13", "43", Grid);
 
-    fn part_1(input: Self::Input) -> Option<String> {
+    const TITLE: &'static str = "Printing Department";
+
+    fn part_1(input: &Self::Input<'_>) -> Option<Answer> {
         let ans = input
             .iter()
             .filter(|(_, t)| **t == Tile::Paper)
@@ -26,10 +28,12 @@ impl Day for Day4 {
             })
             .count();
 
-        Some(ans.to_string())
+        Some(ans.into())
     }
 
-    fn part_2(mut input: Self::Input) -> Option<String> {
+    fn part_2(input: &Self::Input<'_>) -> Option<Answer> {
+        let mut input = input.clone();
+
         let mut i = 0;
 
         loop {
@@ -56,10 +60,10 @@ impl Day for Day4 {
             }
         }
 
-        Some(i.to_string())
+        Some(i.into())
     }
 
-    fn parse_input(input: &str) -> Self::Input {
-        Grid::parse(input)
+    fn try_parse_input(input: &str) -> Result<Self::Input<'_>, InputError> {
+        Ok(Grid::parse(input))
     }
 }
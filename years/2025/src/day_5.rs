@@ -1,4 +1,4 @@
-use advent_core::{day_stuff, ex_for_day, Day};
+use advent_core::{day_stuff, ex_for_day, Answer, Day, InputError};
 use utils::range::BetterRange;
 
 pub struct Day5;
@@ -6,16 +6,19 @@ pub struct Day5;
 impl Day for Day5 {
     day_stuff!(5, "3", "14", (Vec<BetterRange<usize>>, Vec<usize>));
 
-    fn part_1((ranges, ids): Self::Input) -> Option<String> {
+    const TITLE: &'static str = "Cafeteria";
+
+    fn part_1((ranges, ids): &Self::Input<'_>) -> Option<Answer> {
         let ans = ids
-            .into_iter()
+            .iter()
             .filter(|id| ranges.iter().any(|range| range.contains(id)))
             .count();
 
-        Some(ans.to_string())
+        Some(ans.into())
     }
 
-    fn part_2((mut ranges, _): Self::Input) -> Option<String> {
+    fn part_2((ranges, _): &Self::Input<'_>) -> Option<Answer> {
+        let mut ranges = ranges.clone();
         ranges.sort();
 
         let mut new_ranges = Vec::with_capacity(ranges.len());
@@ -41,13 +44,13 @@ impl Day for Day5 {
             .map(|range| range.end - range.start)
             .sum::<usize>();
 
-        Some(ans.to_string())
+        Some(ans.into())
     }
 
-    fn parse_input(input: &str) -> Self::Input {
+    fn try_parse_input(input: &str) -> Result<Self::Input<'_>, InputError> {
         let (ranges, ids) = input.split_once("\n\n").unwrap();
 
-        (
+        Ok((
             ranges
                 .lines()
                 .map(|l| {
@@ -56,6 +59,6 @@ impl Day for Day5 {
                 })
                 .collect(),
             ids.lines().map(|l| l.parse().unwrap()).collect(),
-        )
+        ))
     }
 }
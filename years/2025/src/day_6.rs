@@ -1,4 +1,4 @@
-use advent_core::{day_stuff, ex_for_day, Day};
+use advent_core::{day_stuff, ex_for_day, Answer, Day};
 
 pub struct Day6;
 
@@ -11,7 +11,9 @@ pub enum Prob {
 impl Day for Day6 {
     day_stuff!(6, "4277556", "3263827");
 
-    fn part_1(input: Self::Input) -> Option<String> {
+    const TITLE: &'static str = "Trash Compactor";
+
+    fn part_1(input: &Self::Input<'_>) -> Option<Answer> {
         let uno = input.lines().collect::<Vec<_>>();
         let dos = uno
             .iter()
@@ -45,10 +47,10 @@ impl Day for Day6 {
             })
             .sum::<usize>();
 
-        Some(ans.to_string())
+        Some(ans.into())
     }
 
-    fn part_2(input: Self::Input) -> Option<String> {
+    fn part_2(input: &Self::Input<'_>) -> Option<Answer> {
         let mut cols: Vec<Vec<_>> = vec![];
 
         for row in input.lines() {
@@ -98,6 +100,6 @@ impl Day for Day6 {
 
         tot += curr_val;
 
-        Some(tot.to_string())
+        Some(tot.into())
     }
 }
@@ -1,6 +1,6 @@
 use std::collections::{HashMap, HashSet, VecDeque};
 
-use advent_core::{day_stuff, ex_for_day, Day};
+use advent_core::{day_stuff, ex_for_day, Answer, Day, InputError};
 use utils::{dir::Direction, pos::Position, tiles};
 
 pub struct Day7;
@@ -16,7 +16,9 @@ type Grid = utils::grid::Grid<Tile>;
 impl Day for Day7 {
     day_stuff!(7, "21", "40", Grid);
 
-    fn part_1(input: Self::Input) -> Option<String> {
+    const TITLE: &'static str = "Laboratories";
+
+    fn part_1(input: &Self::Input<'_>) -> Option<Answer> {
         let (start, _) = input.iter().find(|(_, t)| **t == Tile::Start).unwrap();
         let mut queue = VecDeque::<Position>::new();
         let mut seen = HashSet::<Position>::with_capacity(input.size().1);
@@ -44,10 +46,10 @@ impl Day for Day7 {
             }
         }
 
-        Some(splits.to_string())
+        Some(splits.into())
     }
 
-    fn part_2(input: Self::Input) -> Option<String> {
+    fn part_2(input: &Self::Input<'_>) -> Option<Answer> {
         let (start, _) = input.iter().find(|(_, t)| **t == Tile::Start).unwrap();
         let mut queue = VecDeque::<Position>::new();
         let mut seen = HashMap::<Position, usize>::new();
@@ -86,10 +88,10 @@ impl Day for Day7 {
             }
         }
 
-        Some(splits.to_string())
+        Some(splits.into())
     }
 
-    fn parse_input(input: &str) -> Self::Input {
-        Grid::parse(input)
+    fn try_parse_input(input: &str) -> Result<Self::Input<'_>, InputError> {
+        Ok(Grid::parse(input))
     }
 }
@@ -1,6 +1,6 @@
 use std::{collections::HashMap, ops::Sub};
 
-use advent_core::{day_stuff, ex_for_day, Day};
+use advent_core::{day_stuff, ex_for_day, Answer, Day, InputError};
 
 pub struct Day8;
 
@@ -18,7 +18,9 @@ impl Sub for Pos {
 impl Day for Day8 {
     day_stuff!(8, "40", "25272", (usize, Vec<Pos>));
 
-    fn part_1((amnt, input): Self::Input) -> Option<String> {
+    const TITLE: &'static str = "Playground";
+
+    fn part_1((amnt, input): &Self::Input<'_>) -> Option<Answer> {
         let mut circuits = input
             .iter()
             .enumerate()
@@ -33,7 +35,7 @@ impl Day for Day8 {
 
         distances.sort_by_key(|(_, _, x)| *x);
 
-        for (p1, p2, _dist) in distances.into_iter().take(amnt) {
+        for (p1, p2, _dist) in distances.into_iter().take(*amnt) {
             let target_circ = circuits.get(&p1).copied().unwrap();
             let replace_circ = circuits.get(&p2).copied().unwrap();
 
@@ -57,10 +59,10 @@ impl Day for Day8 {
 
         counts.sort_by(|a, b| a.cmp(b).reverse());
 
-        Some(counts.into_iter().take(3).product::<i32>().to_string())
+        Some(counts.into_iter().take(3).product::<i32>().into())
     }
 
-    fn part_2((_amnt, input): Self::Input) -> Option<String> {
+    fn part_2((_amnt, input): &Self::Input<'_>) -> Option<Answer> {
         let mut circuits = input
             .iter()
             .enumerate()
@@ -94,10 +96,10 @@ impl Day for Day8 {
 
         let (a, b) = last_2.expect("Womp womp");
 
-        Some((a.0 * b.0).to_string())
+        Some((a.0 * b.0).into())
     }
 
-    fn parse_input(input: &str) -> Self::Input {
+    fn try_parse_input(input: &str) -> Result<Self::Input<'_>, InputError> {
         let mut lines = input.lines();
 
         let amnt = lines.next().unwrap().parse::<usize>().unwrap();
@@ -110,6 +112,6 @@ impl Day for Day8 {
             })
             .collect();
 
-        (amnt, poses)
+        Ok((amnt, poses))
     }
 }
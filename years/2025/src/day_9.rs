@@ -1,4 +1,4 @@
-use advent_core::{day_stuff, ex_for_day, Day};
+use advent_core::{day_stuff, ex_for_day, Answer, Day, InputError};
 use utils::pos::Position;
 
 pub struct Day9;
@@ -6,7 +6,9 @@ pub struct Day9;
 impl Day for Day9 {
     day_stuff!(9, "50", "24", Vec<Position>);
 
-    fn part_1(input: Self::Input) -> Option<String> {
+    const TITLE: &'static str = "Movie Theater";
+
+    fn part_1(input: &Self::Input<'_>) -> Option<Answer> {
         let ans = input
             .iter()
             .enumerate()
@@ -19,10 +21,12 @@ impl Day for Day9 {
             .max()
             .unwrap();
 
-        Some(ans.to_string())
+        Some(ans.into())
     }
 
-    fn part_2(mut input: Self::Input) -> Option<String> {
+    fn part_2(input: &Self::Input<'_>) -> Option<Answer> {
+        let mut input = input.clone();
+
         let mut max = 0;
 
         input.push(*input.first().unwrap());
@@ -64,11 +68,11 @@ impl Day for Day9 {
             }
         }
 
-        Some(max.to_string())
+        Some(max.into())
     }
 
-    fn parse_input(input: &str) -> Self::Input {
-        input
+    fn try_parse_input(input: &str) -> Result<Self::Input<'_>, InputError> {
+        Ok(input
             .lines()
             .map(|l| {
                 let (x, y) = l.split_once(',').unwrap();
@@ -77,6 +81,6 @@ impl Day for Day9 {
                     y: y.parse().unwrap(),
                 }
             })
-            .collect()
+            .collect())
     }
 }